@@ -1,5 +1,35 @@
+pub const COLOR: &str = "bevy_color::color::Color";
+pub const COLOR_HSLA: &str = "bevy_color::hsla::Hsla";
+pub const COLOR_HSVA: &str = "bevy_color::hsva::Hsva";
+pub const COLOR_HWBA: &str = "bevy_color::hwba::Hwba";
+pub const COLOR_LABA: &str = "bevy_color::laba::Laba";
+pub const COLOR_LCHA: &str = "bevy_color::lcha::Lcha";
+pub const COLOR_LINEAR_RGBA: &str = "bevy_color::linear_rgba::LinearRgba";
+pub const COLOR_OKLABA: &str = "bevy_color::oklaba::Oklaba";
+pub const COLOR_OKLCHA: &str = "bevy_color::oklcha::Oklcha";
+pub const COLOR_SRGBA: &str = "bevy_color::srgba::Srgba";
+pub const COLOR_XYZA: &str = "bevy_color::xyza::Xyza";
 pub const ENTITY: &str = "bevy_ecs::entity::Entity";
+pub const GLAM_DQUAT: &str = "glam::DQuat";
+pub const GLAM_DVEC2: &str = "glam::DVec2";
+pub const GLAM_DVEC3: &str = "glam::DVec3";
+pub const GLAM_DVEC4: &str = "glam::DVec4";
+pub const GLAM_IVEC2: &str = "glam::IVec2";
+pub const GLAM_IVEC3: &str = "glam::IVec3";
+pub const GLAM_IVEC4: &str = "glam::IVec4";
 pub const GLAM_QUAT: &str = "glam::Quat";
+pub const GLAM_UVEC2: &str = "glam::UVec2";
+pub const GLAM_UVEC3: &str = "glam::UVec3";
+pub const GLAM_UVEC4: &str = "glam::UVec4";
 pub const GLAM_VEC2: &str = "glam::Vec2";
 pub const GLAM_VEC3: &str = "glam::Vec3";
 pub const GLAM_VEC3A: &str = "glam::Vec3A";
+pub const GLAM_VEC4: &str = "glam::Vec4";
+/// `Option<T>` type paths start with it
+pub const OPTION_PREFIX: &str = "core::option::Option<";
+
+/// Glam vectors, serialized as `[x, y, ..]` arrays
+pub const GLAM_VECTORS: [&str; 13] = [
+    GLAM_DVEC2, GLAM_DVEC3, GLAM_DVEC4, GLAM_IVEC2, GLAM_IVEC3, GLAM_IVEC4, GLAM_UVEC2, GLAM_UVEC3,
+    GLAM_UVEC4, GLAM_VEC2, GLAM_VEC3, GLAM_VEC3A, GLAM_VEC4,
+];
@@ -0,0 +1,553 @@
+//! `bevy_color` color spaces, converted through linear sRGB so the color
+//! editor can show a swatch and write picked colors back in the space the
+//! color is stored in. Hues are in degrees, the rest of the channels use
+//! Bevy's ranges (e.g. `Laba` lightness is `0.0..=1.5`, not `0..=100`).
+
+use crate::bevy_type;
+
+/// Channels in the order of the struct fields, alpha last.
+pub type Channels = [f64; 4];
+
+/// Variants of `bevy_color::Color`, each a newtype of the same named struct.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorSpace {
+    Srgba,
+    LinearRgba,
+    Hsla,
+    Hsva,
+    Hwba,
+    Laba,
+    Lcha,
+    Oklaba,
+    Oklcha,
+    Xyza,
+}
+
+impl ColorSpace {
+    pub const ALL: [ColorSpace; 10] = [
+        ColorSpace::Srgba,
+        ColorSpace::LinearRgba,
+        ColorSpace::Hsla,
+        ColorSpace::Hsva,
+        ColorSpace::Hwba,
+        ColorSpace::Laba,
+        ColorSpace::Lcha,
+        ColorSpace::Oklaba,
+        ColorSpace::Oklcha,
+        ColorSpace::Xyza,
+    ];
+
+    pub fn from_type_path(type_path: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|space| space.type_path() == type_path)
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|space| space.name() == name)
+    }
+
+    /// `Color` variant name, same as the struct short name
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColorSpace::Srgba => "Srgba",
+            ColorSpace::LinearRgba => "LinearRgba",
+            ColorSpace::Hsla => "Hsla",
+            ColorSpace::Hsva => "Hsva",
+            ColorSpace::Hwba => "Hwba",
+            ColorSpace::Laba => "Laba",
+            ColorSpace::Lcha => "Lcha",
+            ColorSpace::Oklaba => "Oklaba",
+            ColorSpace::Oklcha => "Oklcha",
+            ColorSpace::Xyza => "Xyza",
+        }
+    }
+
+    pub fn type_path(&self) -> &'static str {
+        match self {
+            ColorSpace::Srgba => bevy_type::COLOR_SRGBA,
+            ColorSpace::LinearRgba => bevy_type::COLOR_LINEAR_RGBA,
+            ColorSpace::Hsla => bevy_type::COLOR_HSLA,
+            ColorSpace::Hsva => bevy_type::COLOR_HSVA,
+            ColorSpace::Hwba => bevy_type::COLOR_HWBA,
+            ColorSpace::Laba => bevy_type::COLOR_LABA,
+            ColorSpace::Lcha => bevy_type::COLOR_LCHA,
+            ColorSpace::Oklaba => bevy_type::COLOR_OKLABA,
+            ColorSpace::Oklcha => bevy_type::COLOR_OKLCHA,
+            ColorSpace::Xyza => bevy_type::COLOR_XYZA,
+        }
+    }
+
+    /// Struct field names of the channels
+    pub fn channels(&self) -> [&'static str; 4] {
+        match self {
+            ColorSpace::Srgba | ColorSpace::LinearRgba => ["red", "green", "blue", "alpha"],
+            ColorSpace::Hsla => ["hue", "saturation", "lightness", "alpha"],
+            ColorSpace::Hsva => ["hue", "saturation", "value", "alpha"],
+            ColorSpace::Hwba => ["hue", "whiteness", "blackness", "alpha"],
+            ColorSpace::Laba | ColorSpace::Oklaba => ["lightness", "a", "b", "alpha"],
+            ColorSpace::Lcha | ColorSpace::Oklcha => ["lightness", "chroma", "hue", "alpha"],
+            ColorSpace::Xyza => ["x", "y", "z", "alpha"],
+        }
+    }
+
+    pub fn to_srgba(self, channels: Channels) -> Channels {
+        let [a, b, c, alpha] = channels;
+        let [red, green, blue] = match self {
+            ColorSpace::Srgba => [a, b, c],
+            ColorSpace::Hsla => hsl_to_srgb([a, b, c]),
+            ColorSpace::Hsva => hsv_to_srgb([a, b, c]),
+            ColorSpace::Hwba => hsv_to_srgb(hwb_to_hsv([a, b, c])),
+            _ => self.to_linear([a, b, c]).map(linear_to_srgb),
+        };
+
+        [red, green, blue, alpha]
+    }
+
+    pub fn srgba_to_space(self, srgba: Channels) -> Channels {
+        let [red, green, blue, alpha] = srgba;
+        let srgb = [red, green, blue];
+        let [a, b, c] = match self {
+            ColorSpace::Srgba => srgb,
+            ColorSpace::Hsla => srgb_to_hsl(srgb),
+            ColorSpace::Hsva => srgb_to_hsv(srgb),
+            ColorSpace::Hwba => hsv_to_hwb(srgb_to_hsv(srgb)),
+            _ => self.linear_to_space(srgb.map(srgb_to_linear)),
+        };
+
+        [a, b, c, alpha]
+    }
+
+    fn to_linear(self, channels: [f64; 3]) -> [f64; 3] {
+        match self {
+            ColorSpace::LinearRgba => channels,
+            ColorSpace::Xyza => xyz_to_linear(channels),
+            ColorSpace::Laba => xyz_to_linear(lab_to_xyz(channels)),
+            ColorSpace::Lcha => xyz_to_linear(lab_to_xyz(lch_to_lab(channels))),
+            ColorSpace::Oklaba => oklab_to_linear(channels),
+            ColorSpace::Oklcha => oklab_to_linear(lch_to_lab(channels)),
+            _ => {
+                let [red, green, blue, _] =
+                    self.to_srgba([channels[0], channels[1], channels[2], 1.0]);
+                [red, green, blue].map(srgb_to_linear)
+            }
+        }
+    }
+
+    fn linear_to_space(self, linear: [f64; 3]) -> [f64; 3] {
+        match self {
+            ColorSpace::LinearRgba => linear,
+            ColorSpace::Xyza => linear_to_xyz(linear),
+            ColorSpace::Laba => xyz_to_lab(linear_to_xyz(linear)),
+            ColorSpace::Lcha => lab_to_lch(xyz_to_lab(linear_to_xyz(linear))),
+            ColorSpace::Oklaba => linear_to_oklab(linear),
+            ColorSpace::Oklcha => lab_to_lch(linear_to_oklab(linear)),
+            _ => {
+                let [a, b, c, _] = self.srgba_to_space([
+                    linear_to_srgb(linear[0]),
+                    linear_to_srgb(linear[1]),
+                    linear_to_srgb(linear[2]),
+                    1.0,
+                ]);
+                [a, b, c]
+            }
+        }
+    }
+}
+
+/// `#rrggbb`, clamped to the displayable range
+pub fn srgba_to_hex([red, green, blue, _]: Channels) -> String {
+    let byte = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    format!("#{:02x}{:02x}{:02x}", byte(red), byte(green), byte(blue))
+}
+
+pub fn hex_to_srgb(hex: &str) -> Option<[f64; 3]> {
+    let hex = hex.strip_prefix('#')?;
+
+    if hex.len() != 6 {
+        return None;
+    }
+
+    let channel = |idx: usize| {
+        u8::from_str_radix(hex.get(idx..idx + 2)?, 16)
+            .ok()
+            .map(|value| value as f64 / 255.0)
+    };
+
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+fn srgb_to_linear(value: f64) -> f64 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f64) -> f64 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Hue in degrees of an sRGB color, with its max and min channels
+fn hue([red, green, blue]: [f64; 3]) -> (f64, f64, f64) {
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let delta = max - min;
+
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == red {
+        60.0 * ((green - blue) / delta).rem_euclid(6.0)
+    } else if max == green {
+        60.0 * ((blue - red) / delta + 2.0)
+    } else {
+        60.0 * ((red - green) / delta + 4.0)
+    };
+
+    (hue, max, min)
+}
+
+/// sRGB from hue, chroma and the value added to every channel
+fn hue_to_srgb(hue: f64, chroma: f64, offset: f64) -> [f64; 3] {
+    let hue = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (hue.rem_euclid(2.0) - 1.0).abs());
+    let [red, green, blue] = match hue as u8 {
+        0 => [chroma, x, 0.0],
+        1 => [x, chroma, 0.0],
+        2 => [0.0, chroma, x],
+        3 => [0.0, x, chroma],
+        4 => [x, 0.0, chroma],
+        _ => [chroma, 0.0, x],
+    };
+
+    [red + offset, green + offset, blue + offset]
+}
+
+fn srgb_to_hsl(srgb: [f64; 3]) -> [f64; 3] {
+    let (hue, max, min) = hue(srgb);
+    let lightness = (max + min) / 2.0;
+    let saturation = if max == min {
+        0.0
+    } else {
+        (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+    };
+
+    [hue, saturation, lightness]
+}
+
+fn hsl_to_srgb([hue, saturation, lightness]: [f64; 3]) -> [f64; 3] {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+
+    hue_to_srgb(hue, chroma, lightness - chroma / 2.0)
+}
+
+fn srgb_to_hsv(srgb: [f64; 3]) -> [f64; 3] {
+    let (hue, max, min) = hue(srgb);
+    let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+
+    [hue, saturation, max]
+}
+
+fn hsv_to_srgb([hue, saturation, value]: [f64; 3]) -> [f64; 3] {
+    let chroma = value * saturation;
+
+    hue_to_srgb(hue, chroma, value - chroma)
+}
+
+fn hsv_to_hwb([hue, saturation, value]: [f64; 3]) -> [f64; 3] {
+    [hue, (1.0 - saturation) * value, 1.0 - value]
+}
+
+fn hwb_to_hsv([hue, whiteness, blackness]: [f64; 3]) -> [f64; 3] {
+    // Whiteness and blackness adding up to more than 1 is a shade of gray
+    let total = whiteness + blackness;
+    let (whiteness, blackness) = if total > 1.0 {
+        (whiteness / total, blackness / total)
+    } else {
+        (whiteness, blackness)
+    };
+    let value = 1.0 - blackness;
+    let saturation = if value == 0.0 {
+        0.0
+    } else {
+        1.0 - whiteness / value
+    };
+
+    [hue, saturation, value]
+}
+
+fn linear_to_xyz([red, green, blue]: [f64; 3]) -> [f64; 3] {
+    [
+        0.4124564 * red + 0.3575761 * green + 0.1804375 * blue,
+        0.2126729 * red + 0.7151522 * green + 0.0721750 * blue,
+        0.0193339 * red + 0.1191920 * green + 0.9503041 * blue,
+    ]
+}
+
+fn xyz_to_linear([x, y, z]: [f64; 3]) -> [f64; 3] {
+    [
+        3.2404542 * x - 1.5371385 * y - 0.4985314 * z,
+        -0.9692660 * x + 1.8760108 * y + 0.0415560 * z,
+        0.0556434 * x - 0.2040259 * y + 1.0572252 * z,
+    ]
+}
+
+/// D65 white point
+const WHITE: [f64; 3] = [0.95047, 1.0, 1.08883];
+const CIE_EPSILON: f64 = 216.0 / 24389.0;
+const CIE_KAPPA: f64 = 24389.0 / 27.0;
+
+/// Bevy stores CIE L*a*b* divided by 100
+fn xyz_to_lab(xyz: [f64; 3]) -> [f64; 3] {
+    let [fx, fy, fz] = [0, 1, 2].map(|idx| {
+        let t = xyz[idx] / WHITE[idx];
+
+        if t > CIE_EPSILON {
+            t.cbrt()
+        } else {
+            (CIE_KAPPA * t + 16.0) / 116.0
+        }
+    });
+
+    [
+        (116.0 * fy - 16.0) / 100.0,
+        500.0 * (fx - fy) / 100.0,
+        200.0 * (fy - fz) / 100.0,
+    ]
+}
+
+fn lab_to_xyz([lightness, a, b]: [f64; 3]) -> [f64; 3] {
+    let lightness = lightness * 100.0;
+    let fy = (lightness + 16.0) / 116.0;
+    let fx = a * 100.0 / 500.0 + fy;
+    let fz = fy - b * 100.0 / 200.0;
+    let from_f = |f: f64| {
+        if f.powi(3) > CIE_EPSILON {
+            f.powi(3)
+        } else {
+            (116.0 * f - 16.0) / CIE_KAPPA
+        }
+    };
+    let y = if lightness > CIE_KAPPA * CIE_EPSILON {
+        fy.powi(3)
+    } else {
+        lightness / CIE_KAPPA
+    };
+
+    [from_f(fx) * WHITE[0], y * WHITE[1], from_f(fz) * WHITE[2]]
+}
+
+fn lab_to_lch([lightness, a, b]: [f64; 3]) -> [f64; 3] {
+    let chroma = (a * a + b * b).sqrt();
+    let hue = b.atan2(a).to_degrees().rem_euclid(360.0);
+
+    [lightness, chroma, hue]
+}
+
+fn lch_to_lab([lightness, chroma, hue]: [f64; 3]) -> [f64; 3] {
+    let (sin, cos) = hue.to_radians().sin_cos();
+
+    [lightness, chroma * cos, chroma * sin]
+}
+
+fn linear_to_oklab([red, green, blue]: [f64; 3]) -> [f64; 3] {
+    let l = (0.4122214708 * red + 0.5363325363 * green + 0.0514459929 * blue).cbrt();
+    let m = (0.2119034982 * red + 0.6806995451 * green + 0.1073969566 * blue).cbrt();
+    let s = (0.0883024619 * red + 0.2817188376 * green + 0.6299787005 * blue).cbrt();
+
+    [
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    ]
+}
+
+fn oklab_to_linear([lightness, a, b]: [f64; 3]) -> [f64; 3] {
+    let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+    [
+        4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Channels = [1.0, 0.0, 0.0, 1.0];
+    const GREEN: Channels = [0.0, 1.0, 0.0, 1.0];
+    const BLUE: Channels = [0.0, 0.0, 1.0, 1.0];
+    const WHITE_SRGBA: Channels = [1.0, 1.0, 1.0, 1.0];
+
+    fn assert_approx_eq(a: Channels, b: Channels, epsilon: f64) {
+        assert!(
+            a.iter().zip(b).all(|(a, b)| (a - b).abs() < epsilon),
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let colors = [
+            RED,
+            GREEN,
+            BLUE,
+            WHITE_SRGBA,
+            [0.0, 0.0, 0.0, 1.0],
+            [0.5, 0.5, 0.5, 0.5],
+            [0.2, 0.4, 0.8, 0.25],
+            [0.9, 0.6, 0.1, 0.0],
+        ];
+
+        for space in ColorSpace::ALL {
+            for srgba in colors {
+                let channels = space.srgba_to_space(srgba);
+
+                // The XYZ matrices are rounded to 7 digits, so their round
+                // trip is off by ~1e-6, far below a visible difference
+                assert_approx_eq(space.to_srgba(channels), srgba, 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn alpha_is_kept() {
+        for space in ColorSpace::ALL {
+            assert_eq!(space.srgba_to_space([0.2, 0.4, 0.8, 0.3])[3], 0.3);
+            assert_eq!(space.to_srgba([0.2, 0.4, 0.8, 0.3])[3], 0.3);
+        }
+    }
+
+    #[test]
+    fn linear_reference_values() {
+        let to_linear = |srgba| ColorSpace::LinearRgba.srgba_to_space(srgba);
+
+        assert_approx_eq(
+            to_linear([0.5, 0.0, 1.0, 1.0]),
+            [0.214041, 0.0, 1.0, 1.0],
+            1e-6,
+        );
+        // Linear segment below 0.04045
+        assert_approx_eq(
+            to_linear([0.04, 0.0, 0.0, 1.0]),
+            [0.04 / 12.92, 0.0, 0.0, 1.0],
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn hsl_hsv_hwb_reference_values() {
+        let orange = [1.0, 0.5, 0.0, 1.0];
+
+        assert_approx_eq(
+            ColorSpace::Hsla.srgba_to_space(orange),
+            [30.0, 1.0, 0.5, 1.0],
+            1e-9,
+        );
+        assert_approx_eq(
+            ColorSpace::Hsva.srgba_to_space(orange),
+            [30.0, 1.0, 1.0, 1.0],
+            1e-9,
+        );
+        assert_approx_eq(
+            ColorSpace::Hwba.srgba_to_space(orange),
+            [30.0, 0.0, 0.0, 1.0],
+            1e-9,
+        );
+        assert_approx_eq(
+            ColorSpace::Hsla.srgba_to_space(BLUE),
+            [240.0, 1.0, 0.5, 1.0],
+            1e-9,
+        );
+
+        // Whiteness and blackness over 1 are normalized to a gray
+        assert_approx_eq(
+            ColorSpace::Hwba.to_srgba([0.0, 1.0, 1.0, 1.0]),
+            [0.5, 0.5, 0.5, 1.0],
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn xyz_reference_values() {
+        assert_approx_eq(
+            ColorSpace::Xyza.srgba_to_space(RED),
+            [0.412456, 0.212673, 0.019334, 1.0],
+            1e-6,
+        );
+        assert_approx_eq(
+            ColorSpace::Xyza.srgba_to_space(WHITE_SRGBA),
+            [0.95047, 1.0, 1.08883, 1.0],
+            1e-4,
+        );
+    }
+
+    #[test]
+    fn lab_reference_values() {
+        // CIE L*a*b* (D65) divided by 100, as Bevy stores it
+        assert_approx_eq(
+            ColorSpace::Laba.srgba_to_space(RED),
+            [0.532408, 0.800925, 0.672032, 1.0],
+            1e-4,
+        );
+        assert_approx_eq(
+            ColorSpace::Laba.srgba_to_space(GREEN),
+            [0.877347, -0.861827, 0.831793, 1.0],
+            1e-4,
+        );
+        assert_approx_eq(
+            ColorSpace::Laba.srgba_to_space(WHITE_SRGBA),
+            [1.0, 0.0, 0.0, 1.0],
+            1e-4,
+        );
+        assert_approx_eq(
+            ColorSpace::Lcha.srgba_to_space(RED),
+            [0.532408, 1.045518, 39.9990, 1.0],
+            1e-3,
+        );
+    }
+
+    #[test]
+    fn oklab_reference_values() {
+        assert_approx_eq(
+            ColorSpace::Oklaba.srgba_to_space(RED),
+            [0.627955, 0.224863, 0.125846, 1.0],
+            1e-5,
+        );
+        assert_approx_eq(
+            ColorSpace::Oklaba.srgba_to_space(BLUE),
+            [0.452014, -0.032457, -0.311528, 1.0],
+            1e-5,
+        );
+        assert_approx_eq(
+            ColorSpace::Oklaba.srgba_to_space(WHITE_SRGBA),
+            [1.0, 0.0, 0.0, 1.0],
+            1e-5,
+        );
+        assert_approx_eq(
+            ColorSpace::Oklcha.srgba_to_space(RED),
+            [0.627955, 0.257683, 29.2339, 1.0],
+            1e-3,
+        );
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(srgba_to_hex([1.0, 0.5, 0.0, 1.0]), "#ff8000");
+        assert_eq!(srgba_to_hex([-0.5, 2.0, 0.0, 1.0]), "#00ff00");
+        assert_eq!(hex_to_srgb("#ff8000"), Some([1.0, 128.0 / 255.0, 0.0]));
+        assert_eq!(hex_to_srgb("ff8000"), None);
+        assert_eq!(hex_to_srgb("#ff80"), None);
+        assert_eq!(hex_to_srgb("#gg8000"), None);
+    }
+}
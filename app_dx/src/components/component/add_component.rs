@@ -0,0 +1,107 @@
+use client::{Entity, ScriptStep, Value, default_value, from_str, to_string_pretty};
+use dioxus::prelude::*;
+use std::collections::BTreeMap;
+
+use crate::{
+    components::Icon,
+    states::{AuditLogState, ConnectionState},
+    utils::get_component_types,
+};
+
+/// Picker to insert a new component into an entity. Only lists registered
+/// types that reflect `Component` and that the entity doesn't have yet.
+#[component]
+pub fn AddComponent(id: Entity, existing: Vec<String>) -> Element {
+    let client = use_context::<ConnectionState>().client;
+    let schema = use_context::<ConnectionState>().schema;
+    let mut update_signal = use_context::<ConnectionState>().update_signal;
+    let mut audit_log = use_context::<AuditLogState>();
+    let mut selected = use_signal(|| None::<String>);
+    let mut value = use_signal(|| "{}".to_string());
+    let mut error = use_signal(|| None::<String>);
+
+    let available = get_component_types(&schema.read())
+        .into_iter()
+        .filter(|(type_path, _)| !existing.contains(type_path))
+        .collect::<Vec<_>>();
+
+    let submit = move |_| {
+        let Some(type_path) = selected() else {
+            return;
+        };
+
+        let component_value = match from_str::<Value>(&value()) {
+            Ok(component_value) => component_value,
+            Err(e) => {
+                error.set(Some(format!("Invalid JSON: {e}")));
+                return;
+            }
+        };
+
+        spawn(async move {
+            let components = BTreeMap::from([(type_path, component_value)]);
+
+            match client().insert_components(id, components.clone()).await {
+                Ok(_) => {
+                    audit_log.record(ScriptStep::InsertComponents {
+                        entity: audit_log.entity_ref(id),
+                        components,
+                    });
+                    selected.set(None);
+                    value.set("{}".to_string());
+                    error.set(None);
+                    update_signal.set(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "add-component",
+            div { class: "select",
+                select {
+                    onchange: move |e| {
+                        let type_path = e.value();
+                        let default = default_value(&type_path, &schema.peek())
+                            .and_then(|default| to_string_pretty(&default).ok());
+
+                        error.set(None);
+                        value.set(default.unwrap_or_else(|| "{}".to_string()));
+                        selected.set((!type_path.is_empty()).then_some(type_path));
+                    },
+                    option { value: "", selected: selected().is_none(), "Add component…" }
+                    for (type_path , short_path) in available {
+                        option {
+                            key: "{type_path}",
+                            value: "{type_path}",
+                            title: "{type_path}",
+                            selected: selected().as_ref() == Some(&type_path),
+                            "{short_path}"
+                        }
+                    }
+                }
+            }
+
+            if selected().is_some() {
+                textarea {
+                    class: "add-component__value",
+                    value: value(),
+                    autocomplete: "off",
+                    autocapitalize: "off",
+                    spellcheck: "false",
+                    oninput: move |e| value.set(e.value()),
+                }
+
+                if let Some(error) = error() {
+                    div { class: "issue", "{error}" }
+                }
+
+                button { class: "button add-component__submit", onclick: submit,
+                    {Icon::Add.render()}
+                    "Add"
+                }
+            }
+        }
+    }
+}
@@ -1,19 +1,21 @@
-use client::{Entity, Value, component};
-use dioxus::{logger::tracing::info, prelude::*};
+use client::{Entity, ScriptStep, Value, component};
+use dioxus::prelude::*;
 
 use crate::{
-    components::{ComponentValue, Icon},
-    states::ConnectionState,
+    components::{
+        ComponentValue, Icon, component::transform_panel::TransformPanel, use_inspector_edits,
+    },
+    states::{AuditLogState, ConnectionState, MutationTarget, NotificationsState},
     utils::get_short_type_name,
 };
 
 #[derive(Debug)]
 pub struct MutateData {
-    /// Component type path, e.g. `bevy_ui::ui_node::Node`
-    type_path: String,
+    /// Component or resource type path, e.g. `bevy_ui::ui_node::Node`
+    pub type_path: String,
     /// Path to the field in the component, e.g. `.align_content`
-    path: String,
-    value: Value,
+    pub path: String,
+    pub value: Value,
 }
 
 impl MutateData {
@@ -31,9 +33,18 @@ impl MutateData {
 }
 
 #[component]
-pub fn ComponentInspector(id: Entity, type_path: String, value: Option<Value>) -> Element {
+pub fn ComponentInspector(
+    id: Entity,
+    type_path: String,
+    value: ReadOnlySignal<Option<Value>>,
+    /// `GlobalTransform` of the entity, shown next to its `Transform`
+    global_transform: Option<Value>,
+) -> Element {
     let client = use_context::<ConnectionState>().client;
     let schema = use_context::<ConnectionState>().schema;
+    let mut update_signal = use_context::<ConnectionState>().update_signal;
+    let mut notifications = use_context::<NotificationsState>();
+    let mut audit_log = use_context::<AuditLogState>();
     let mut is_open = use_signal(|| {
         ![
             component::COMPUTED_NODE,
@@ -59,13 +70,30 @@ pub fn ComponentInspector(id: Entity, type_path: String, value: Option<Value>) -
         let type_path = type_path.clone();
         move || schema().get(&type_path).cloned()
     });
-    let mutate_cb = use_callback(move |data: MutateData| {
-        info!("MutateData: {data:?}");
-        spawn(async move {
-            let _ = client()
-                .mutate_component(id, data.type_path, data.path, data.value)
-                .await;
-        });
+    let (edits, mutate_cb) = use_inspector_edits(value, bevy_type, move |type_path| {
+        MutationTarget::Component(id, type_path.to_string())
+    });
+    let remove_cb = use_callback({
+        let type_path = type_path.clone();
+        move |_| {
+            let type_path = type_path.clone();
+            spawn(async move {
+                let title = format!("Failed to remove `{}`", get_short_type_name(&type_path));
+                let res = client()
+                    .remove_components(id, vec![type_path.clone()])
+                    .await;
+
+                match res {
+                    Ok(_) => audit_log.record(ScriptStep::RemoveComponents {
+                        entity: audit_log.entity_ref(id),
+                        components: vec![type_path],
+                    }),
+                    Err(e) => notifications.push(title, e),
+                }
+
+                update_signal.set(());
+            });
+        }
     });
 
     rsx! {
@@ -76,6 +104,15 @@ pub fn ComponentInspector(id: Entity, type_path: String, value: Option<Value>) -
                 onclick: move |_| is_open.set(!is_open()),
                 span { {get_short_type_name(&type_path)} }
                 {Icon::ChevronDown.render_with_class("component__open-icon")}
+                div {
+                    class: "component__remove",
+                    title: "Remove component",
+                    onclick: move |e| {
+                        e.stop_propagation();
+                        remove_cb.call(());
+                    },
+                    {Icon::Delete.render()}
+                }
             }
 
             div { class: "component__value",
@@ -83,13 +120,24 @@ pub fn ComponentInspector(id: Entity, type_path: String, value: Option<Value>) -
                     {
                         match bevy_type() {
                             Some(bevy_type) => {
-                                if let Some(value) = value {
-                                    rsx! {
-                                        ComponentValue {
-                                            value: value.clone(),
-                                            component_type: bevy_type.type_path.clone(),
-                                            bevy_type: bevy_type.clone(),
-                                            mutate_cb,
+                                if let Some(value) = value() {
+                                    if bevy_type.type_path == component::TRANSFORM {
+                                        rsx! {
+                                            TransformPanel {
+                                                value: edits.apply(value, Some(&bevy_type), &schema.read()),
+                                                global: global_transform.clone(),
+                                                bevy_type: bevy_type.clone(),
+                                                mutate_cb,
+                                            }
+                                        }
+                                    } else {
+                                        rsx! {
+                                            ComponentValue {
+                                                value: edits.apply(value, Some(&bevy_type), &schema.read()),
+                                                component_type: bevy_type.type_path.clone(),
+                                                bevy_type: bevy_type.clone(),
+                                                mutate_cb,
+                                            }
                                         }
                                     }
                                 } else {
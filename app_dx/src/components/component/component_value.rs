@@ -3,18 +3,23 @@ use dioxus::prelude::*;
 
 use crate::{
     bevy_type,
+    color::ColorSpace,
     components::{
         JsonValue,
         component::{
-            value_bool::ValueBool, value_entity::ValueEntity, value_number::ValueNumber,
-            value_select::ValueSelect, value_string::ValueString,
+            transform_panel::GlobalTransformValue, validated_submit::use_validated_submit,
+            value_bool::ValueBool, value_color::ValueColor, value_entity::ValueEntity,
+            value_enum::ValueEnum, value_list::ValueList, value_map::ValueMap,
+            value_number::ValueNumber, value_quat::ValueQuat, value_select::ValueSelect,
+            value_set::ValueSet, value_string::ValueString, value_tuple::ValueTuple,
+            value_vector::ValueVector,
         },
     },
     states::ConnectionState,
-    utils::{get_array_path, get_object_path, get_type_path_from_ref_value, value_to_string},
+    utils::{get_object_path, get_type_path_from_ref_value, value_to_string},
 };
 
-use super::{MutateData, map_value::map_value};
+use super::{EditStatus, MutateData, PendingEdits};
 
 #[component]
 pub fn ComponentValue(
@@ -25,13 +30,21 @@ pub fn ComponentValue(
     parent_path: Option<String>,
 ) -> Element {
     let schema = use_context::<ConnectionState>().schema;
-    let value = map_value(&bevy_type.type_path, value);
-    let path = use_signal(|| parent_path.clone().unwrap_or_default());
+    // Read on every render, collection items move when others are added,
+    // removed or renamed
+    let path = parent_path.clone().unwrap_or_default();
+    let edits = try_use_context::<PendingEdits>();
+    // Leaf editors submit through here
+    let (submit, mut invalid) = use_validated_submit(
+        component_type.clone(),
+        path.clone(),
+        bevy_type.clone(),
+        mutate_cb,
+    );
     let read_only = [
         component::COMPUTED_NODE,
         component::COMPUTED_TEXT_BLOCK,
         component::COMPUTED_UI_TARGET_CAMERA,
-        component::GLOBAL_TRANSFORM,
         component::MONITOR,
         component::POINTER_PRESS,
         component::TEXT_LAYOUT_INFO,
@@ -39,11 +52,11 @@ pub fn ComponentValue(
 
     if read_only.contains(&bevy_type.type_path.as_str()) {
         return rsx! {
-            JsonValue { value: value.clone(), parent_path: path() }
+            JsonValue { value: value.clone(), parent_path: path }
         };
     }
 
-    match bevy_type.type_path.as_str() {
+    let element = match bevy_type.type_path.as_str() {
         bevy_type::ENTITY => {
             let entity = from_value::<Entity>(value);
 
@@ -56,10 +69,43 @@ pub fn ComponentValue(
                 },
             }
         }
+        type_path
+            if type_path == bevy_type::COLOR || ColorSpace::from_type_path(type_path).is_some() =>
+        {
+            rsx! {
+                ValueColor {
+                    value,
+                    component_type,
+                    bevy_type: bevy_type.clone(),
+                    mutate_cb,
+                    parent_path,
+                }
+            }
+        }
+        bevy_type::GLAM_QUAT | bevy_type::GLAM_DQUAT => rsx! {
+            ValueQuat {
+                value,
+                component_type,
+                mutate_cb,
+                parent_path,
+            }
+        },
+        type_path if bevy_type::GLAM_VECTORS.contains(&type_path) => rsx! {
+            ValueVector {
+                value,
+                component_type,
+                bevy_type: bevy_type.clone(),
+                mutate_cb,
+                parent_path,
+            }
+        },
+        component::GLOBAL_TRANSFORM => rsx! {
+            GlobalTransformValue { value }
+        },
         component::NAME => rsx! {
             ValueString {
                 value: value_to_string(&value),
-                onchange: move |value| mutate_cb.call(MutateData::new(&component_type, path(), value)),
+                onchange: move |value: String| submit.call(value.into()),
             }
         },
         // bevy_type::FLEX_DIRECTION => {
@@ -69,91 +115,80 @@ pub fn ComponentValue(
             (SchemaType::Boolean, SchemaKind::Value) => rsx! {
                 ValueBool {
                     value: value.as_bool().unwrap_or_default(),
-                    onchange: move |value| mutate_cb.call(MutateData::new(&component_type, path(), value)),
+                    onchange: move |value: bool| submit.call(value.into()),
                 }
             },
             (SchemaType::Float, SchemaKind::Value)
             | (SchemaType::Int, SchemaKind::Value)
             | (SchemaType::Uint, SchemaKind::Value) => rsx! {
                 ValueNumber {
-                    value: value_to_string(&value),
+                    value,
                     schema_type: bevy_type.schema_type.clone(),
-                    onchange: move |value| mutate_cb.call(MutateData::new(&component_type, path(), value)),
+                    onchange: move |value| submit.call(value),
                 }
             },
             (SchemaType::String, SchemaKind::Value) => rsx! {
                 ValueString {
                     value: value_to_string(&value),
-                    onchange: move |value| mutate_cb.call(MutateData::new(&component_type, path(), value)),
+                    onchange: move |value: String| submit.call(value.into()),
                 }
             },
             (SchemaType::String, SchemaKind::Enum) => rsx! {
                 ValueSelect {
                     value: value_to_string(&value),
                     options: bevy_type.one_of.iter().map(value_to_string).collect(),
-                    onchange: move |value| mutate_cb.call(MutateData::new(&component_type, path(), value)),
+                    onchange: move |value: String| submit.call(value.into()),
                 }
             },
-            (SchemaType::Array, SchemaKind::List) => {
-                let value = value.as_array().cloned().unwrap_or_else(Vec::new);
-                let type_ref = bevy_type.items.unwrap();
-                let bevy_type = get_type_path_from_ref_value(&type_ref)
-                    .and_then(|type_path| schema().get(&type_path).cloned());
-
-                match bevy_type {
-                    Some(bevy_type) => rsx! {
-                        table { class: "json-value-table json-value-table--array",
-                            if value.is_empty() {
-                                div { class: "json-value-empty", "Empty Array" }
-                            } else {
-                                for (idx , item) in value.iter().enumerate() {
-                                    {
-                                        let item_path = get_array_path(&parent_path, idx.to_string().as_str());
-                                        rsx! {
-                                            tr {
-                                                th { title: "{item_path}", "⚬" }
-                                                td {
-                                                    ComponentValue {
-                                                        value: item.clone(),
-                                                        component_type: component_type.clone(),
-                                                        bevy_type: bevy_type.clone(),
-                                                        mutate_cb,
-                                                        parent_path: item_path.clone(),
-                                                    }
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    },
-                    None => rsx! {
-                        div { class: "issue", "Type not found: {type_ref:?}" }
-                    },
+            (SchemaType::Object, SchemaKind::Enum) => rsx! {
+                ValueEnum {
+                    value,
+                    component_type,
+                    bevy_type: bevy_type.clone(),
+                    mutate_cb,
+                    parent_path,
                 }
-            }
-            (SchemaType::Array, SchemaKind::TupleStruct) => {
-                let type_ref = bevy_type.prefix_items.first().unwrap();
-                let parent_path = get_object_path(&parent_path, "0");
-                let bevy_type = get_type_path_from_ref_value(type_ref)
-                    .and_then(|type_path| schema().get(&type_path).cloned());
-
-                match bevy_type {
-                    Some(bevy_type) => rsx! {
-                        ComponentValue {
-                            value,
-                            component_type,
-                            bevy_type,
-                            mutate_cb,
-                            parent_path,
-                        }
-                    },
-                    None => rsx! {
-                        div { class: "issue", "Type not found: {type_ref:?}" }
-                    },
+            },
+            (SchemaType::Object, SchemaKind::Map) => rsx! {
+                ValueMap {
+                    value,
+                    component_type,
+                    bevy_type: bevy_type.clone(),
+                    mutate_cb,
+                    parent_path,
                 }
-            }
+            },
+            (_, SchemaKind::Set) => rsx! {
+                ValueSet {
+                    value,
+                    component_type,
+                    bevy_type: bevy_type.clone(),
+                    mutate_cb,
+                    parent_path,
+                }
+            },
+            (SchemaType::Array, SchemaKind::List) => rsx! {
+                ValueList {
+                    value,
+                    component_type,
+                    bevy_type: bevy_type.clone(),
+                    mutate_cb,
+                    parent_path,
+                }
+            },
+            (SchemaType::Array, SchemaKind::TupleStruct)
+            | (SchemaType::Array, SchemaKind::Tuple) => rsx! {
+                ValueTuple {
+                    value,
+                    component_type,
+                    // Only tuple structs are flattened, `(f32,)` is still an array
+                    is_newtype: bevy_type.kind == SchemaKind::TupleStruct
+                        && bevy_type.prefix_items.len() == 1,
+                    prefix_items: bevy_type.prefix_items.clone(),
+                    mutate_cb,
+                    parent_path,
+                }
+            },
             //     }
             // },
             (SchemaType::Object, SchemaKind::Struct) => {
@@ -217,5 +252,41 @@ pub fn ComponentValue(
                 JsonValue { value }
             },
         },
+    };
+
+    if let Some(error) = invalid() {
+        return rsx! {
+            div { class: "value-edit value-edit--failed",
+                {element}
+                div {
+                    class: "value-edit__error",
+                    title: "Dismiss",
+                    onclick: move |_| invalid.set(None),
+                    "{error}"
+                }
+            }
+        };
+    }
+
+    match edits.and_then(|edits| edits.status(&path)) {
+        Some(EditStatus::Pending) => rsx! {
+            div { class: "value-edit value-edit--pending", {element} }
+        },
+        Some(EditStatus::Failed(error)) => rsx! {
+            div { class: "value-edit value-edit--failed",
+                {element}
+                div {
+                    class: "value-edit__error",
+                    title: "Dismiss",
+                    onclick: move |_| {
+                        if let Some(mut edits) = edits {
+                            edits.dismiss(&path);
+                        }
+                    },
+                    "{error.message}"
+                }
+            }
+        },
+        _ => element,
     }
 }
@@ -1,11 +1,25 @@
+mod add_component;
 mod component_inspector;
 mod component_value;
-mod map_value;
+mod pending_edits;
+mod scrub_number;
+mod transform_panel;
+mod validated_submit;
 mod value_bool;
+mod value_color;
 mod value_entity;
+mod value_enum;
+mod value_list;
+mod value_map;
 mod value_number;
+mod value_quat;
 mod value_select;
+mod value_set;
 mod value_string;
+mod value_tuple;
+mod value_vector;
 
+pub use add_component::*;
 pub use component_inspector::*;
 pub use component_value::*;
+pub use pending_edits::*;
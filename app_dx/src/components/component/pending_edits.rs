@@ -0,0 +1,221 @@
+use client::{JsonSchemaBevyType, Value};
+use dioxus::prelude::*;
+use std::collections::BTreeMap;
+
+use crate::{
+    components::MutateData,
+    states::{
+        ConnectionState, ErrorMessage, HistoryState, MutationQueueState, MutationTarget,
+        NotificationsState,
+    },
+    utils::{get_short_type_name, get_value_at_path_mut},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EditStatus {
+    /// Sent, waiting for the server response
+    Pending,
+    /// Accepted by the server, but the refreshed value hasn't arrived yet
+    Confirmed,
+    /// Rejected by the server, the value is rolled back
+    Failed(ErrorMessage),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Edit {
+    id: u32,
+    value: Value,
+    pub status: EditStatus,
+}
+
+/// Optimistic edits of a component (or resource) keyed by field path, so the
+/// UI shows the new value right away and rolls it back if the server rejects
+/// it. Provided as context by the inspectors, read by `ComponentValue`.
+#[derive(Clone, Copy)]
+pub struct PendingEdits {
+    edits: Signal<BTreeMap<String, Edit>>,
+    next_id: Signal<u32>,
+}
+
+impl PendingEdits {
+    pub fn new() -> Self {
+        Self {
+            edits: Signal::new(BTreeMap::new()),
+            next_id: Signal::new(0),
+        }
+    }
+
+    /// Registers an edit as pending and returns its ID. A newer edit of the
+    /// same path replaces the previous one.
+    pub fn start(&mut self, path: impl Into<String>, value: Value) -> u32 {
+        let id = self.next_id.cloned();
+
+        self.next_id += 1;
+        self.edits.write().insert(
+            path.into(),
+            Edit {
+                id,
+                value,
+                status: EditStatus::Pending,
+            },
+        );
+
+        id
+    }
+
+    pub fn confirm(&mut self, path: &str, id: u32) {
+        self.set_status(path, id, EditStatus::Confirmed);
+    }
+
+    pub fn fail(&mut self, path: &str, id: u32, error: ErrorMessage) {
+        self.set_status(path, id, EditStatus::Failed(error));
+    }
+
+    pub fn dismiss(&mut self, path: &str) {
+        self.edits.write().remove(path);
+    }
+
+    pub fn status(&self, path: &str) -> Option<EditStatus> {
+        self.edits.read().get(path).map(|edit| edit.status.clone())
+    }
+
+    /// Drops confirmed edits, call it when a fresh value arrives from the
+    /// server.
+    pub fn clear_confirmed(&mut self) {
+        let has_confirmed = self
+            .edits
+            .peek()
+            .values()
+            .any(|edit| edit.status == EditStatus::Confirmed);
+
+        if has_confirmed {
+            self.edits
+                .write()
+                .retain(|_, edit| edit.status != EditStatus::Confirmed);
+        }
+    }
+
+    /// Applies the pending and confirmed edits on top of the server value of
+    /// `bevy_type`.
+    pub fn apply(
+        &self,
+        value: Value,
+        bevy_type: Option<&JsonSchemaBevyType>,
+        schema: &BTreeMap<String, JsonSchemaBevyType>,
+    ) -> Value {
+        self.apply_if(value, bevy_type, schema, |status| {
+            !matches!(status, EditStatus::Failed(_))
+        })
+    }
+
+    /// Applies only the confirmed edits, i.e. the value the server holds even
+    /// if the refreshed one hasn't arrived yet.
+    pub fn apply_confirmed(
+        &self,
+        value: Value,
+        bevy_type: Option<&JsonSchemaBevyType>,
+        schema: &BTreeMap<String, JsonSchemaBevyType>,
+    ) -> Value {
+        self.apply_if(value, bevy_type, schema, |status| {
+            *status == EditStatus::Confirmed
+        })
+    }
+
+    fn apply_if(
+        &self,
+        mut value: Value,
+        bevy_type: Option<&JsonSchemaBevyType>,
+        schema: &BTreeMap<String, JsonSchemaBevyType>,
+        predicate: impl Fn(&EditStatus) -> bool,
+    ) -> Value {
+        for (path, edit) in self.edits.read().iter() {
+            if !predicate(&edit.status) {
+                continue;
+            }
+
+            if let Some(field) = get_value_at_path_mut(&mut value, path, bevy_type, schema) {
+                *field = edit.value.clone();
+            }
+        }
+
+        value
+    }
+
+    fn set_status(&mut self, path: &str, id: u32, status: EditStatus) {
+        let mut edits = self.edits.write();
+
+        // Ignore responses of edits that were replaced by a newer one
+        if let Some(edit) = edits.get_mut(path).filter(|edit| edit.id == id) {
+            edit.status = status;
+        }
+    }
+}
+
+/// Provides the [`PendingEdits`] of an inspector and returns them along with
+/// its `mutate_cb`, which shows edits right away, sends them through the
+/// mutation queue, records them in the history once the server accepts them
+/// and reports the failures. `value` is the server value of `bevy_type`.
+pub fn use_inspector_edits(
+    value: ReadOnlySignal<Option<Value>>,
+    bevy_type: Memo<Option<JsonSchemaBevyType>>,
+    target: impl Fn(&str) -> MutationTarget + 'static,
+) -> (PendingEdits, Callback<MutateData>) {
+    let schema = use_context::<ConnectionState>().schema;
+    let mut update_signal = use_context::<ConnectionState>().update_signal;
+    let mut notifications = use_context::<NotificationsState>();
+    let mut mutation_queue = use_context::<MutationQueueState>();
+    let mut history = use_context::<HistoryState>();
+    let mut edits = use_context_provider(PendingEdits::new);
+
+    let mutate_cb = use_callback(move |data: MutateData| {
+        let target = target(&data.type_path);
+
+        // The value being replaced, as held by the server. Edits still in
+        // flight may be replaced or rejected, so they're left out
+        let bevy_type = bevy_type.peek();
+        let schema = schema.peek();
+        let old = value
+            .peek()
+            .clone()
+            .map(|value| edits.apply_confirmed(value, bevy_type.as_ref(), &schema))
+            .and_then(|mut value| {
+                get_value_at_path_mut(&mut value, &data.path, bevy_type.as_ref(), &schema).cloned()
+            });
+
+        let edit_id = edits.start(&data.path, data.value.clone());
+        let title = format!(
+            "Failed to mutate `{}{}`",
+            get_short_type_name(&data.type_path),
+            data.path
+        );
+        let res = mutation_queue.mutate(target.clone(), data.path.clone(), data.value.clone());
+
+        spawn(async move {
+            match res.await {
+                Some(Ok(_)) => {
+                    if let Some(old) = old {
+                        history.record(target, data.path.clone(), old, data.value);
+                    }
+
+                    edits.confirm(&data.path, edit_id);
+                }
+                Some(Err(e)) => {
+                    edits.fail(&data.path, edit_id, (&e).into());
+                    notifications.push(title, e);
+                }
+                // Replaced by a newer edit of the same field
+                None => return,
+            }
+
+            update_signal.set(());
+        });
+    });
+
+    // Confirmed edits are replaced by the fresh server value
+    use_effect(move || {
+        value.read();
+        edits.clear_confirmed();
+    });
+
+    (edits, mutate_cb)
+}
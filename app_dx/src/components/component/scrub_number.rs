@@ -0,0 +1,80 @@
+use client::{SchemaType, Value};
+use dioxus::prelude::*;
+
+use crate::{components::component::value_number::ValueNumber, numeric::Numeric};
+
+/// Number input with a label that changes the value when dragged
+/// horizontally, `step` per pixel (×10 holding Shift, ×0.1 holding Alt).
+#[component]
+pub fn ScrubNumber(
+    label: String,
+    value: Value,
+    schema_type: SchemaType,
+    step: f64,
+    onchange: EventHandler<Value>,
+) -> Element {
+    // Pointer X and value when the drag started
+    let mut drag = use_signal(|| None::<(f64, f64)>);
+    let start_value = match Numeric::from_value(&value, &schema_type) {
+        Some(Numeric::Float(number)) => Some(number),
+        Some(Numeric::Int(text)) => text.parse::<f64>().ok(),
+        _ => None,
+    };
+
+    rsx! {
+        div { class: "scrub-number",
+            div {
+                class: "scrub-number__label scrub-number__label--{label.to_lowercase()}",
+                title: "Drag to change",
+                onmousedown: move |e| {
+                    if let Some(start_value) = start_value {
+                        e.prevent_default();
+                        drag.set(Some((e.client_coordinates().x, start_value)));
+                    }
+                },
+                "{label}"
+            }
+            ValueNumber { value, schema_type: schema_type.clone(), onchange }
+            if drag().is_some() {
+                // Catches the pointer anywhere in the window while dragging
+                div {
+                    class: "scrub-number__overlay",
+                    onmousemove: move |e| {
+                        let Some((start_x, start_value)) = drag() else {
+                            return;
+                        };
+                        let modifiers = e.modifiers();
+                        let speed = if modifiers.shift() {
+                            10.0
+                        } else if modifiers.alt() {
+                            0.1
+                        } else {
+                            1.0
+                        };
+                        let number = start_value + (e.client_coordinates().x - start_x) * step * speed;
+
+                        if let Some(value) = scrubbed_value(number, step * speed, &schema_type) {
+                            onchange.call(value);
+                        }
+                    },
+                    onmouseup: move |_| drag.set(None),
+                }
+            }
+        }
+    }
+}
+
+/// Rounds to the step precision, so dragging doesn't produce values like
+/// `0.30000000000000004`.
+fn scrubbed_value(number: f64, step: f64, schema_type: &SchemaType) -> Option<Value> {
+    let numeric = match schema_type {
+        SchemaType::Int => Numeric::Int((number.round() as i64).to_string()),
+        SchemaType::Uint => Numeric::Int((number.round().max(0.0) as u64).to_string()),
+        _ => {
+            let decimals = (-step.log10()).ceil().max(0.0) as usize;
+            Numeric::Float(format!("{number:.decimals$}").parse().ok()?)
+        }
+    };
+
+    numeric.to_value().ok()
+}
@@ -0,0 +1,250 @@
+use client::{JsonSchemaBevyType, SchemaType, Value, json};
+use dioxus::prelude::*;
+
+use crate::{
+    components::{ComponentValue, Icon, JsonValue, component::scrub_number::ScrubNumber},
+    math::{Quat, Vec3, affine_decompose, quat_to_euler},
+    states::ConnectionState,
+    utils::get_type_path_from_ref_value,
+};
+
+use super::MutateData;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Channel {
+    Translation,
+    Rotation,
+    Scale,
+}
+
+impl Channel {
+    const ALL: [Channel; 3] = [Channel::Translation, Channel::Rotation, Channel::Scale];
+
+    /// `Transform` field name
+    fn key(&self) -> &'static str {
+        match self {
+            Channel::Translation => "translation",
+            Channel::Rotation => "rotation",
+            Channel::Scale => "scale",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Channel::Translation => "Translation",
+            Channel::Rotation => "Rotation",
+            Channel::Scale => "Scale",
+        }
+    }
+
+    /// Value of `Transform::IDENTITY`
+    fn reset_value(&self) -> Value {
+        match self {
+            Channel::Translation => json!([0.0, 0.0, 0.0]),
+            Channel::Rotation => json!([0.0, 0.0, 0.0, 1.0]),
+            Channel::Scale => json!([1.0, 1.0, 1.0]),
+        }
+    }
+}
+
+/// Editor for `Transform`, with the entity's `GlobalTransform` decomposed
+/// next to it. Rotation is edited as Euler angles, scale can be locked to
+/// change all the axes proportionally, and each channel can be reset.
+#[component]
+pub fn TransformPanel(
+    value: Value,
+    global: Option<Value>,
+    bevy_type: JsonSchemaBevyType,
+    mutate_cb: Callback<MutateData>,
+) -> Element {
+    let schema = use_context::<ConnectionState>().schema;
+    let mut is_scale_locked = use_signal(|| false);
+    let global = global.as_ref().and_then(read_affine).map(affine_decompose);
+    let class = if global.is_some() {
+        "transform-panel"
+    } else {
+        "transform-panel transform-panel--local"
+    };
+
+    rsx! {
+        div { class,
+            if global.is_some() {
+                div {}
+                div { class: "transform-panel__heading", "Local" }
+                div { class: "transform-panel__heading", "Global" }
+            }
+            for channel in Channel::ALL {
+                div { key: "{channel.key()}-label", class: "transform-panel__label",
+                    span { class: "transform-panel__name", "{channel.label()}" }
+                    if channel == Channel::Scale {
+                        div {
+                            class: if is_scale_locked() { "transform-panel__action transform-panel__action--active" } else { "transform-panel__action" },
+                            title: if is_scale_locked() { "Unlock uniform scale" } else { "Lock uniform scale" },
+                            onclick: move |_| is_scale_locked.toggle(),
+                            if is_scale_locked() {
+                                {Icon::Lock.render()}
+                            } else {
+                                {Icon::Unlock.render()}
+                            }
+                        }
+                    }
+                    div {
+                        class: "transform-panel__action",
+                        title: "Reset {channel.key()}",
+                        onclick: {
+                            let component_type = bevy_type.type_path.clone();
+                            let path = format!(".{}", channel.key());
+                            move |_| {
+                                mutate_cb.call(MutateData::new(&component_type, &path, channel.reset_value()));
+                            }
+                        },
+                        {Icon::Undo.render()}
+                    }
+                }
+                div { key: "{channel.key()}-local", class: "transform-panel__local",
+                    {
+                        let field_value = value.get(channel.key()).cloned();
+                        let field_type = bevy_type
+                            .properties
+                            .get(channel.key())
+                            .and_then(get_type_path_from_ref_value)
+                            .and_then(|type_path| schema().get(&type_path).cloned());
+
+                        match (field_value, field_type) {
+                            (Some(field_value), _) if channel == Channel::Scale && is_scale_locked() => {
+                                let component_type = bevy_type.type_path.clone();
+
+                                rsx! {
+                                    UniformScale {
+                                        value: field_value,
+                                        onchange: move |scale: Vec3| {
+                                            mutate_cb.call(MutateData::new(&component_type, ".scale", json!(scale)));
+                                        },
+                                    }
+                                }
+                            }
+                            (Some(field_value), Some(field_type)) => rsx! {
+                                ComponentValue {
+                                    value: field_value,
+                                    component_type: bevy_type.type_path.clone(),
+                                    bevy_type: field_type,
+                                    mutate_cb,
+                                    parent_path: format!(".{}", channel.key()),
+                                }
+                            },
+                            _ => rsx! {
+                                div { class: "issue", "Can't unwrap value." }
+                            },
+                        }
+                    }
+                }
+                if let Some((translation, rotation, scale)) = global {
+                    div { key: "{channel.key()}-global", class: "transform-panel__global",
+                        match channel {
+                            Channel::Translation => readout(["X", "Y", "Z"], translation, ""),
+                            Channel::Rotation => rotation_readout(rotation),
+                            Channel::Scale => readout(["X", "Y", "Z"], scale, ""),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Read only `GlobalTransform`, decomposed into translation, rotation and
+/// scale instead of the raw affine matrix.
+#[component]
+pub fn GlobalTransformValue(value: Value) -> Element {
+    let Some((translation, rotation, scale)) = read_affine(&value).map(affine_decompose) else {
+        return rsx! {
+            JsonValue { value }
+        };
+    };
+
+    rsx! {
+        div { class: "json-value-key-list",
+            for (key , element) in [
+                ("translation", readout(["X", "Y", "Z"], translation, "")),
+                ("rotation", rotation_readout(rotation)),
+                ("scale", readout(["X", "Y", "Z"], scale, "")),
+            ]
+            {
+                div { key, class: "json-value-key-list__item",
+                    div { class: "json-value-key-list__key", "{key}" }
+                    div { class: "json-value-key-list__value", {element} }
+                }
+            }
+        }
+    }
+}
+
+/// Scale editor that keeps the proportions between the axes. Scrubbing an
+/// axis at `0` sets all of them to the new value.
+#[component]
+fn UniformScale(value: Value, onchange: EventHandler<Vec3>) -> Element {
+    let mut scale = [1.0; 3];
+
+    for (idx, axis) in scale.iter_mut().enumerate() {
+        if let Some(number) = value.get(idx).and_then(Value::as_f64) {
+            *axis = number;
+        }
+    }
+
+    rsx! {
+        div { class: "value-vector",
+            for (idx , axis) in ["X", "Y", "Z"].into_iter().enumerate() {
+                ScrubNumber {
+                    key: "{axis}",
+                    label: axis,
+                    value: value.get(idx).cloned().unwrap_or_default(),
+                    schema_type: SchemaType::Float,
+                    step: 0.01,
+                    onchange: move |value: Value| {
+                        let Some(number) = value.as_f64() else {
+                            return;
+                        };
+                        let ratio = number / scale[idx];
+
+                        onchange.call(if ratio.is_finite() { scale.map(|axis| axis * ratio) } else { [number; 3] });
+                    },
+                }
+            }
+        }
+    }
+}
+
+fn rotation_readout(rotation: Quat) -> Element {
+    readout(["Yaw", "Pitch", "Roll"], quat_to_euler(rotation), "°")
+}
+
+fn readout(labels: [&str; 3], values: Vec3, unit: &str) -> Element {
+    rsx! {
+        div { class: "value-vector",
+            for (label , value) in labels.into_iter().zip(values) {
+                div { key: "{label}", class: "transform-panel__readout",
+                    span { class: "transform-panel__axis", "{label}" }
+                    // `-0.000` reads as a different value than `0.000`
+                    span { "{(value * 1000.0).round() / 1000.0 + 0.0:.3}{unit}" }
+                }
+            }
+        }
+    }
+}
+
+/// `Affine3A` as serialized by glam, 12 numbers. `None` for other shapes and
+/// non-finite (`null`) items.
+fn read_affine(value: &Value) -> Option<[f64; 12]> {
+    let items = value.as_array()?;
+    let mut affine = [0.0; 12];
+
+    if items.len() != affine.len() {
+        return None;
+    }
+
+    for (number, item) in affine.iter_mut().zip(items) {
+        *number = item.as_f64()?;
+    }
+
+    Some(affine)
+}
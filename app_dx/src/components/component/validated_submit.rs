@@ -0,0 +1,31 @@
+use client::{JsonSchemaBevyType, ValidationError, Value, validate};
+use dioxus::prelude::*;
+
+use crate::states::ConnectionState;
+
+use super::MutateData;
+
+/// Submit callback of the value editors. Values are validated against
+/// `bevy_type` before mutating `path`, invalid ones aren't sent but kept in
+/// the returned signal, so the editor can report them in place.
+pub fn use_validated_submit(
+    component_type: String,
+    path: String,
+    bevy_type: JsonSchemaBevyType,
+    mutate_cb: Callback<MutateData>,
+) -> (Callback<Value>, Signal<Option<ValidationError>>) {
+    let schema = use_context::<ConnectionState>().schema;
+    let mut invalid = use_signal(|| None::<ValidationError>);
+    let submit =
+        use_callback(
+            move |value: Value| match validate(&value, &bevy_type, &schema.peek()) {
+                Ok(()) => {
+                    invalid.set(None);
+                    mutate_cb.call(MutateData::new(&component_type, &path, value));
+                }
+                Err(error) => invalid.set(Some(error)),
+            },
+        );
+
+    (submit, invalid)
+}
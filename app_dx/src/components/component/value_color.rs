@@ -0,0 +1,172 @@
+use client::{JsonSchemaBevyType, Map, SchemaType, Value, json};
+use dioxus::prelude::*;
+
+use crate::{
+    bevy_type,
+    color::{Channels, ColorSpace, hex_to_srgb, srgba_to_hex},
+    components::component::{
+        scrub_number::ScrubNumber, validated_submit::use_validated_submit,
+        value_select::ValueSelect,
+    },
+};
+
+use super::MutateData;
+
+/// Editor for `Color` and the `bevy_color` structs (`Srgba`, `Hsla`, ...):
+/// a swatch with the system color picker and a field per channel. Colors are
+/// edited in the space they're stored in, `Color` can also be switched to
+/// another space, converting the current color. Every change submits the
+/// whole color.
+#[component]
+pub fn ValueColor(
+    value: Value,
+    component_type: String,
+    bevy_type: JsonSchemaBevyType,
+    mutate_cb: Callback<MutateData>,
+    parent_path: Option<String>,
+) -> Element {
+    let is_enum = bevy_type.type_path == bevy_type::COLOR;
+    let (submit, invalid) = use_validated_submit(
+        component_type,
+        parent_path.unwrap_or_default(),
+        bevy_type.clone(),
+        mutate_cb,
+    );
+    let submit_channels = move |space: ColorSpace, channels: Channels| {
+        let payload = channels_value(space, channels);
+
+        // `Color` is serialized as `{ "Srgba": { "red": .. } }`
+        submit.call(if is_enum {
+            json!({ space.name(): payload })
+        } else {
+            payload
+        });
+    };
+
+    // `Color` is serialized as `{ "Srgba": { "red": .. } }`
+    let (space, payload) = match &value {
+        Value::Object(object) if is_enum => match object.iter().next() {
+            Some((name, payload)) => (ColorSpace::from_name(name), payload.clone()),
+            None => (None, Value::Null),
+        },
+        value => (
+            ColorSpace::from_type_path(&bevy_type.type_path),
+            value.clone(),
+        ),
+    };
+
+    let Some(space) = space else {
+        return rsx! {
+            div { class: "issue", "Unknown color space: {value}" }
+        };
+    };
+
+    let channels = read_channels(space, &payload);
+    let [red, green, blue, alpha] = space
+        .to_srgba(channels)
+        .map(|channel| channel.clamp(0.0, 1.0));
+
+    rsx! {
+        div { class: "value-color",
+            div { class: "value-color__header",
+                div {
+                    class: "value-color__swatch",
+                    title: "Pick a color",
+                    div {
+                        class: "value-color__swatch-fill",
+                        style: "background-color: rgba({red * 255.0}, {green * 255.0}, {blue * 255.0}, {alpha})",
+                    }
+                    input {
+                        r#type: "color",
+                        value: srgba_to_hex([red, green, blue, alpha]),
+                        oninput: move |e| {
+                            let Some([red, green, blue]) = hex_to_srgb(&e.value()) else {
+                                return;
+                            };
+
+                            // Keep the stored alpha, the picker has none
+                            let picked = space.srgba_to_space([red, green, blue, channels[3]]);
+
+                            submit_channels(space, picked.map(round_channel));
+                        },
+                    }
+                }
+                if is_enum {
+                    ValueSelect {
+                        value: space.name(),
+                        options: ColorSpace::ALL.iter().map(|space| space.name().to_string()).collect(),
+                        onchange: move |name: String| {
+                            let Some(new_space) = ColorSpace::from_name(&name) else {
+                                return;
+                            };
+
+                            if new_space != space {
+                                let converted = new_space.srgba_to_space(space.to_srgba(channels));
+
+                                submit_channels(new_space, converted.map(round_channel));
+                            }
+                        },
+                    }
+                }
+            }
+            div { class: "value-vector",
+                for (idx , channel) in space.channels().into_iter().enumerate() {
+                    ScrubNumber {
+                        key: "{channel}",
+                        label: channel_label(channel),
+                        value: payload.get(channel).cloned().unwrap_or_default(),
+                        schema_type: SchemaType::Float,
+                        step: if channel == "hue" { 1.0 } else { 0.01 },
+                        onchange: move |value: Value| {
+                            let Some(number) = value.as_f64() else {
+                                return;
+                            };
+                            let mut channels = channels;
+
+                            channels[idx] = number;
+                            submit_channels(space, channels);
+                        },
+                    }
+                }
+            }
+            if let Some(error) = invalid() {
+                div { class: "value-edit__error", "{error}" }
+            }
+        }
+    }
+}
+
+/// Missing or non-finite (`null`) channels are read as `0`.
+fn read_channels(space: ColorSpace, payload: &Value) -> Channels {
+    space.channels().map(|channel| {
+        payload
+            .get(channel)
+            .and_then(Value::as_f64)
+            .unwrap_or_default()
+    })
+}
+
+fn channels_value(space: ColorSpace, channels: Channels) -> Value {
+    Value::Object(
+        space
+            .channels()
+            .into_iter()
+            .zip(channels)
+            .map(|(channel, number)| (channel.to_string(), json!(number)))
+            .collect::<Map<_, _>>(),
+    )
+}
+
+/// Hides float noise from conversions, `f32` channels can't keep more
+/// precision anyway.
+fn round_channel(number: f64) -> f64 {
+    (number * 1e6).round() / 1e6
+}
+
+/// `L`, `C`, `H`, ... `a` and `alpha` would clash, alpha is `α`.
+fn channel_label(channel: &str) -> String {
+    match channel {
+        "alpha" => "α".to_string(),
+        channel => channel.chars().take(1).collect::<String>().to_uppercase(),
+    }
+}
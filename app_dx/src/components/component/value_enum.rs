@@ -0,0 +1,169 @@
+use client::{JsonSchemaBevyType, SchemaKind, Value, default_variant_value, from_value};
+use dioxus::prelude::*;
+
+use crate::{
+    bevy_type,
+    components::component::{
+        ComponentValue, validated_submit::use_validated_submit, value_select::ValueSelect,
+        value_tuple::ValueTuple,
+    },
+    states::ConnectionState,
+    utils::{get_object_path, get_type_path_from_ref_value},
+};
+
+use super::MutateData;
+
+/// A variant of an enum with tuple or struct variants, from its `oneOf`
+/// schema entry.
+#[derive(Clone, Debug, PartialEq)]
+struct Variant {
+    name: String,
+    /// `None` for unit variants
+    payload: Option<JsonSchemaBevyType>,
+}
+
+impl Variant {
+    fn from_schema(entry: &Value) -> Option<Self> {
+        let name = entry.get("shortPath")?.as_str()?.to_string();
+        // Unit variant entries have no `type`, so they don't parse
+        let payload = from_value::<JsonSchemaBevyType>(entry.clone())
+            .ok()
+            .filter(|payload| matches!(payload.kind, SchemaKind::Struct | SchemaKind::Tuple));
+
+        Some(Self { name, payload })
+    }
+}
+
+/// Editor for enums with data-carrying variants: a variant picker followed
+/// by the editors of the current variant payload.
+///
+/// Payload fields are mutated in place (`.field` for struct variants, `.0`,
+/// `.1`, ... for tuple variants), switching the variant replaces the whole
+/// enum with a default payload.
+#[component]
+pub fn ValueEnum(
+    value: Value,
+    component_type: String,
+    bevy_type: JsonSchemaBevyType,
+    mutate_cb: Callback<MutateData>,
+    parent_path: Option<String>,
+) -> Element {
+    let schema = use_context::<ConnectionState>().schema;
+    let path = parent_path.clone().unwrap_or_default();
+    let is_option = bevy_type.type_path.starts_with(bevy_type::OPTION_PREFIX);
+    let variants = bevy_type
+        .one_of
+        .iter()
+        .filter_map(Variant::from_schema)
+        .collect::<Vec<_>>();
+    let (name, payload) = split_variant(&value, is_option);
+    let current = name.clone();
+    let variant = variants
+        .iter()
+        .find(|variant| variant.name == name)
+        .cloned();
+
+    let (submit, invalid) =
+        use_validated_submit(component_type.clone(), path, bevy_type.clone(), mutate_cb);
+
+    let onchange = {
+        let type_path = bevy_type.type_path.clone();
+
+        move |new_name: String| {
+            if new_name == name {
+                return;
+            }
+
+            if let Some(value) = default_variant_value(&type_path, &new_name, &schema.peek()) {
+                submit.call(value);
+            }
+        }
+    };
+
+    rsx! {
+        div { class: "value-enum",
+            ValueSelect {
+                value: current,
+                options: variants.iter().map(|variant| variant.name.clone()).collect(),
+                onchange,
+            }
+            match variant.and_then(|variant| variant.payload) {
+                Some(payload_type) if payload_type.kind == SchemaKind::Struct => {
+                    let mut properties = payload_type.properties.iter().collect::<Vec<_>>();
+
+                    properties.sort_by_key(|(key, _)| *key);
+
+                    rsx! {
+                        div { class: "value-enum__payload json-value-key-list",
+                            for (key , type_ref) in properties {
+                                {
+                                    let prop_type = get_type_path_from_ref_value(type_ref)
+                                        .and_then(|type_path| schema().get(&type_path).cloned());
+
+                                    match (prop_type, payload.get(key)) {
+                                        (Some(prop_type), Some(value)) => rsx! {
+                                            div { key, class: "json-value-key-list__item",
+                                                div { class: "json-value-key-list__key", "{key}" }
+                                                div { class: "json-value-key-list__value",
+                                                    ComponentValue {
+                                                        value: value.clone(),
+                                                        component_type: component_type.clone(),
+                                                        bevy_type: prop_type,
+                                                        mutate_cb,
+                                                        parent_path: get_object_path(&parent_path, key),
+                                                    }
+                                                }
+                                            }
+                                        },
+                                        _ => rsx! {
+                                            div { class: "issue", "Unknown property type." }
+                                        },
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                Some(payload_type) => rsx! {
+                    div { class: "value-enum__payload",
+                        ValueTuple {
+                            value: payload,
+                            component_type,
+                            is_newtype: payload_type.prefix_items.len() == 1,
+                            prefix_items: payload_type.prefix_items,
+                            mutate_cb,
+                            parent_path,
+                        }
+                    }
+                },
+                None => rsx! {},
+            }
+            if let Some(error) = invalid() {
+                div { class: "value-edit__error", "{error}" }
+            }
+        }
+    }
+}
+
+/// Splits a serialized enum into variant name and payload. Unit variants are
+/// serialized as `"Variant"`, the rest as `{ "Variant": payload }`. `Option`
+/// is special cased by `bevy_reflect`: `None` is `null` and `Some(value)` is
+/// just `value`.
+fn split_variant(value: &Value, is_option: bool) -> (String, Value) {
+    if is_option {
+        return match value {
+            Value::Null => ("None".to_string(), Value::Null),
+            value => ("Some".to_string(), value.clone()),
+        };
+    }
+
+    match value {
+        Value::String(name) => (name.clone(), Value::Null),
+        Value::Object(object) if object.len() == 1 => object
+            .iter()
+            .next()
+            .map(|(name, payload)| (name.clone(), payload.clone()))
+            .unwrap_or_default(),
+        _ => Default::default(),
+    }
+}
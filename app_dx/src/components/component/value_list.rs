@@ -0,0 +1,147 @@
+use client::{JsonSchemaBevyType, Value, default_value_for_ref};
+use dioxus::prelude::*;
+
+use crate::{
+    components::{
+        Icon,
+        component::{ComponentValue, validated_submit::use_validated_submit},
+    },
+    states::ConnectionState,
+    utils::{get_array_path, get_type_path_from_ref_value},
+};
+
+use super::MutateData;
+
+/// Editor for `SchemaKind::List` types. Items are mutated in place (`[0]`,
+/// `[1].field`, ...), adding, removing and moving items submits the whole
+/// list.
+#[component]
+pub fn ValueList(
+    value: Value,
+    component_type: String,
+    bevy_type: JsonSchemaBevyType,
+    mutate_cb: Callback<MutateData>,
+    parent_path: Option<String>,
+) -> Element {
+    let schema = use_context::<ConnectionState>().schema;
+    let items = value.as_array().cloned().unwrap_or_default();
+    let item_type = bevy_type
+        .items
+        .as_ref()
+        .and_then(get_type_path_from_ref_value)
+        .and_then(|type_path| schema().get(&type_path).cloned());
+
+    let (submit, invalid) = use_validated_submit(
+        component_type.clone(),
+        parent_path.clone().unwrap_or_default(),
+        bevy_type.clone(),
+        mutate_cb,
+    );
+    let add = {
+        let items = items.clone();
+        let item_ref = bevy_type.items.clone();
+
+        move |_| {
+            let mut items = items.clone();
+            let item = item_ref
+                .as_ref()
+                .and_then(|item_ref| default_value_for_ref(item_ref, &schema.peek()))
+                .unwrap_or_default();
+
+            items.push(item);
+            submit.call(items.into());
+        }
+    };
+
+    let Some(item_type) = item_type else {
+        return rsx! {
+            div { class: "issue", "Type not found: {bevy_type.items:?}" }
+        };
+    };
+
+    rsx! {
+        div { class: "value-collection",
+            table { class: "json-value-table json-value-table--array",
+                if items.is_empty() {
+                    div { class: "json-value-empty", "Empty Array" }
+                } else {
+                    for (idx , item) in items.iter().enumerate() {
+                        {
+                            let item_path = get_array_path(&parent_path, idx.to_string().as_str());
+                            let is_last = idx + 1 == items.len();
+                            let swap = {
+                                let items = items.clone();
+
+                                move |other: usize| {
+                                    let mut items = items.clone();
+
+                                    items.swap(idx, other);
+                                    submit.call(items.into());
+                                }
+                            };
+                            let remove = {
+                                let items = items.clone();
+
+                                move |_| {
+                                    let mut items = items.clone();
+
+                                    items.remove(idx);
+                                    submit.call(items.into());
+                                }
+                            };
+
+                            rsx! {
+                                tr {
+                                    th { title: "{item_path}", "⚬" }
+                                    td {
+                                        ComponentValue {
+                                            value: item.clone(),
+                                            component_type: component_type.clone(),
+                                            bevy_type: item_type.clone(),
+                                            mutate_cb,
+                                            parent_path: item_path.clone(),
+                                        }
+                                    }
+                                    td { class: "value-collection__actions",
+                                        if idx > 0 {
+                                            div {
+                                                class: "value-collection__action",
+                                                title: "Move up",
+                                                onclick: {
+                                                    let swap = swap.clone();
+                                                    move |_| swap(idx - 1)
+                                                },
+                                                {Icon::ArrowUp.render()}
+                                            }
+                                        }
+                                        if !is_last {
+                                            div {
+                                                class: "value-collection__action",
+                                                title: "Move down",
+                                                onclick: move |_| swap(idx + 1),
+                                                {Icon::ArrowDown.render()}
+                                            }
+                                        }
+                                        div {
+                                            class: "value-collection__action",
+                                            title: "Remove item",
+                                            onclick: remove,
+                                            {Icon::Close.render()}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            button { class: "button value-collection__add", onclick: add,
+                {Icon::Add.render()}
+                "Add item"
+            }
+            if let Some(error) = invalid() {
+                div { class: "value-edit__error", "{error}" }
+            }
+        }
+    }
+}
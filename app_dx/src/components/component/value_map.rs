@@ -0,0 +1,219 @@
+use client::{JsonSchemaBevyType, Map, SchemaType, Value, default_value_for_ref, from_str};
+use dioxus::prelude::*;
+
+use crate::{
+    components::{
+        Icon,
+        component::{ComponentValue, validated_submit::use_validated_submit},
+    },
+    states::ConnectionState,
+    utils::{
+        get_array_path, get_type_path_from_ref_value, get_value_at_path_mut, split_index_path,
+        value_to_string,
+    },
+};
+
+use super::{MutateData, PendingEdits};
+
+/// Editor for `SchemaKind::Map` types. Reflect paths can't reach into maps,
+/// so every change, nested value edits included, submits the whole map.
+#[component]
+pub fn ValueMap(
+    value: Value,
+    component_type: String,
+    bevy_type: JsonSchemaBevyType,
+    mutate_cb: Callback<MutateData>,
+    parent_path: Option<String>,
+) -> Element {
+    let schema = use_context::<ConnectionState>().schema;
+    // Entry editors use paths relative to the map (`[0]`, `[1].field`, ...),
+    // keep their pending edits apart from the inspector ones
+    use_context_provider(PendingEdits::new);
+    let map = value.as_object().cloned().unwrap_or_default();
+    let key_type = bevy_type
+        .key_type
+        .as_ref()
+        .and_then(get_type_path_from_ref_value)
+        .and_then(|type_path| schema().get(&type_path).cloned());
+    let value_type = bevy_type
+        .value_type
+        .as_ref()
+        .and_then(get_type_path_from_ref_value)
+        .and_then(|type_path| schema().get(&type_path).cloned());
+
+    let (submit, invalid) = use_validated_submit(
+        component_type.clone(),
+        parent_path.clone().unwrap_or_default(),
+        bevy_type.clone(),
+        mutate_cb,
+    );
+    let key_cb = use_callback({
+        let map = map.clone();
+        move |data: MutateData| {
+            let Some((old_key, _)) =
+                split_index_path(&data.path).and_then(|(idx, _)| map.iter().nth(idx))
+            else {
+                return;
+            };
+            let new_key = value_to_string(&data.value);
+
+            // Renaming onto an existing key would silently drop an entry
+            if map.contains_key(&new_key) {
+                return;
+            }
+
+            let map = map
+                .iter()
+                .map(|(key, value)| {
+                    let key = if key == old_key { &new_key } else { key };
+                    (key.clone(), value.clone())
+                })
+                .collect();
+
+            submit.call(map);
+        }
+    });
+    let value_cb = use_callback({
+        let map = map.clone();
+        let value_type = value_type.clone();
+        move |data: MutateData| {
+            let mut map = map.clone();
+            let schema = schema.peek();
+            let entry = split_index_path(&data.path).and_then(|(idx, rest)| {
+                let value = map.values_mut().nth(idx)?;
+                get_value_at_path_mut(value, rest, value_type.as_ref(), &schema)
+            });
+
+            if let Some(entry) = entry {
+                *entry = data.value;
+                submit.call(Value::Object(map));
+            }
+        }
+    });
+    let add = {
+        let map = map.clone();
+        let key_type = key_type.clone();
+        let value_ref = bevy_type.value_type.clone();
+
+        move |_| {
+            let mut map = map.clone();
+            let key = new_key(&map, key_type.as_ref());
+            let value = value_ref
+                .as_ref()
+                .and_then(|value_ref| default_value_for_ref(value_ref, &schema.peek()))
+                .unwrap_or_default();
+
+            map.insert(key, value);
+            submit.call(Value::Object(map));
+        }
+    };
+
+    rsx! {
+        div { class: "value-collection",
+            table { class: "json-value-table",
+                if map.is_empty() {
+                    div { class: "json-value-empty", "Empty Map" }
+                } else {
+                    for (idx , (key , item)) in map.iter().enumerate() {
+                        {
+                            let entry_path = get_array_path(&None, &idx.to_string());
+                            let remove = {
+                                let map = map.clone();
+                                let key = key.clone();
+
+                                move |_| {
+                                    let mut map = map.clone();
+
+                                    map.remove(&key);
+                                    submit.call(Value::Object(map));
+                                }
+                            };
+
+                            rsx! {
+                                tr { key: "{key}",
+                                    th {
+                                        match &key_type {
+                                            Some(key_type) => rsx! {
+                                                ComponentValue {
+                                                    value: key_value(key, key_type),
+                                                    component_type: component_type.clone(),
+                                                    bevy_type: key_type.clone(),
+                                                    mutate_cb: key_cb,
+                                                    parent_path: entry_path.clone(),
+                                                }
+                                            },
+                                            None => rsx! { "{key}" },
+                                        }
+                                    }
+                                    td {
+                                        match &value_type {
+                                            Some(value_type) => rsx! {
+                                                ComponentValue {
+                                                    value: item.clone(),
+                                                    component_type: component_type.clone(),
+                                                    bevy_type: value_type.clone(),
+                                                    mutate_cb: value_cb,
+                                                    parent_path: entry_path,
+                                                }
+                                            },
+                                            None => rsx! {
+                                                div { class: "issue", "Type not found: {bevy_type.value_type:?}" }
+                                            },
+                                        }
+                                    }
+                                    td { class: "value-collection__actions",
+                                        div {
+                                            class: "value-collection__action",
+                                            title: "Remove entry",
+                                            onclick: remove,
+                                            {Icon::Close.render()}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            button {
+                class: "button value-collection__add",
+                disabled: key_type.is_none(),
+                onclick: add,
+                {Icon::Add.render()}
+                "Add entry"
+            }
+            if let Some(error) = invalid() {
+                div { class: "value-edit__error", "{error}" }
+            }
+        }
+    }
+}
+
+/// Map keys are serialized as JSON object keys, i.e. strings, even when the
+/// key type is a number or a bool.
+fn key_value(key: &str, key_type: &JsonSchemaBevyType) -> Value {
+    match key_type.schema_type {
+        SchemaType::String => Value::String(key.to_string()),
+        _ => from_str(key).unwrap_or_else(|_| Value::String(key.to_string())),
+    }
+}
+
+/// First free key: `0`, `1`, ... for numeric keys, `key`, `key_1`, ... for
+/// the rest.
+fn new_key(map: &Map<String, Value>, key_type: Option<&JsonSchemaBevyType>) -> String {
+    let is_numeric = key_type.is_some_and(|key_type| {
+        matches!(
+            key_type.schema_type,
+            SchemaType::Int | SchemaType::Uint | SchemaType::Float
+        )
+    });
+
+    (0..)
+        .map(|n| match (is_numeric, n) {
+            (true, n) => n.to_string(),
+            (false, 0) => "key".to_string(),
+            (false, n) => format!("key_{n}"),
+        })
+        .find(|key| !map.contains_key(key))
+        .unwrap_or_default()
+}
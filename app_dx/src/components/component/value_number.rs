@@ -1,27 +1,40 @@
-use client::SchemaType;
+use client::{SchemaType, Value};
 use dioxus::prelude::*;
 
+use crate::numeric::Numeric;
+
+/// Number input that keeps integers exact and flags non-finite floats, see
+/// [`Numeric`]. Only emits values that can be sent.
 #[component]
-pub fn ValueNumber(value: String, onchange: EventHandler<f64>, schema_type: SchemaType) -> Element {
-    let (min_attr, step_attr) = match schema_type {
-        SchemaType::Float => (None, Some("any".to_string())),
-        SchemaType::Int => (None, Some("1".to_string())),
-        SchemaType::Uint => (Some("0".to_string()), Some("1".to_string())),
-        _ => (None, None),
-    };
+pub fn ValueNumber(
+    value: Value,
+    onchange: EventHandler<Value>,
+    schema_type: SchemaType,
+) -> Element {
+    let mut error = use_signal(|| None::<String>);
+    let number = Numeric::from_value(&value, &schema_type);
+    let is_non_finite = number == Some(Numeric::NonFinite);
+    let text = number.map(|number| number.to_string()).unwrap_or_default();
 
     rsx! {
         input {
-            class: "value-number",
-            r#type: "number",
-            value,
-            min: min_attr,
-            step: step_attr,
+            class: if is_non_finite { "value-number value-number--non-finite" } else { "value-number" },
+            r#type: "text",
+            inputmode: if schema_type == SchemaType::Float { "decimal" } else { "numeric" },
+            title: if is_non_finite { "NaN or infinity, BRP sends both as `null`" },
+            value: text,
             oninput: move |e| {
-                if let Ok(number) = e.value().parse::<f64>() {
-                    onchange.call(number);
+                match Numeric::parse(&e.value(), &schema_type).and_then(|number| number.to_value()) {
+                    Ok(number) => {
+                        error.set(None);
+                        onchange.call(number);
+                    }
+                    Err(message) => error.set(Some(message)),
                 }
             },
         }
+        if let Some(error) = error() {
+            div { class: "value-edit__error", "{error}" }
+        }
     }
 }
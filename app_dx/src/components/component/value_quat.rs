@@ -0,0 +1,74 @@
+use client::{SchemaType, Value, json};
+use dioxus::prelude::*;
+
+use crate::{
+    components::component::scrub_number::ScrubNumber,
+    math::{Euler, QUAT_IDENTITY, Quat, quat_approx_eq, quat_from_euler, quat_to_euler},
+};
+
+use super::MutateData;
+
+/// Editor for `Quat` and `DQuat` as yaw, pitch and roll in degrees. Every
+/// change submits the whole, normalized, quaternion.
+#[component]
+pub fn ValueQuat(
+    value: Value,
+    component_type: String,
+    mutate_cb: Callback<MutateData>,
+    parent_path: Option<String>,
+) -> Element {
+    // Angles of the last edit. The same rotation has many Euler angles, keep
+    // the ones the user typed while the quaternion doesn't change elsewhere.
+    let mut edited = use_signal(|| None::<(Quat, Euler)>);
+    let quat = read_quat(&value);
+    let angles = match edited() {
+        Some((edited_quat, angles)) if quat_approx_eq(edited_quat, quat) => angles,
+        _ => quat_to_euler(quat),
+    };
+
+    rsx! {
+        div { class: "value-vector",
+            for (idx , label) in ["Yaw", "Pitch", "Roll"].into_iter().enumerate() {
+                ScrubNumber {
+                    key: "{label}",
+                    label,
+                    // Hide float noise from the conversion, e.g. `29.999999999999996`
+                    value: json!((angles[idx] * 1000.0).round() / 1000.0),
+                    schema_type: SchemaType::Float,
+                    step: 0.5,
+                    onchange: {
+                        let component_type = component_type.clone();
+                        let path = parent_path.clone().unwrap_or_default();
+
+                        move |value: Value| {
+                            let Some(angle) = value.as_f64() else {
+                                return;
+                            };
+                            let mut angles = angles;
+
+                            angles[idx] = angle;
+
+                            let quat = quat_from_euler(angles);
+
+                            edited.set(Some((quat, angles)));
+                            mutate_cb.call(MutateData::new(&component_type, &path, json!(quat)));
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Missing or non-finite (`null`) components fall back to identity.
+fn read_quat(value: &Value) -> Quat {
+    let mut quat = QUAT_IDENTITY;
+
+    for (idx, component) in quat.iter_mut().enumerate() {
+        if let Some(number) = value.get(idx).and_then(Value::as_f64) {
+            *component = number;
+        }
+    }
+
+    quat
+}
@@ -0,0 +1,139 @@
+use client::{JsonSchemaBevyType, Value, default_value_for_ref};
+use dioxus::prelude::*;
+
+use crate::{
+    components::{
+        Icon,
+        component::{ComponentValue, validated_submit::use_validated_submit},
+    },
+    states::ConnectionState,
+    utils::{get_array_path, get_type_path_from_ref_value, get_value_at_path_mut},
+};
+
+use super::{MutateData, PendingEdits};
+
+/// Editor for `SchemaKind::Set` types. Reflect paths can't reach into sets,
+/// so every change, nested item edits included, submits the whole set.
+#[component]
+pub fn ValueSet(
+    value: Value,
+    component_type: String,
+    bevy_type: JsonSchemaBevyType,
+    mutate_cb: Callback<MutateData>,
+    parent_path: Option<String>,
+) -> Element {
+    let schema = use_context::<ConnectionState>().schema;
+    // Item editors use paths relative to the set (`[0]`, `[1].field`, ...),
+    // keep their pending edits apart from the inspector ones
+    use_context_provider(PendingEdits::new);
+    let items = value.as_array().cloned().unwrap_or_default();
+    let item_type = bevy_type
+        .items
+        .as_ref()
+        .and_then(get_type_path_from_ref_value)
+        .and_then(|type_path| schema().get(&type_path).cloned());
+    let default_item = bevy_type
+        .items
+        .as_ref()
+        .and_then(|item_ref| default_value_for_ref(item_ref, &schema()))
+        .unwrap_or_default();
+
+    let (submit, invalid) = use_validated_submit(
+        component_type.clone(),
+        parent_path.clone().unwrap_or_default(),
+        bevy_type.clone(),
+        mutate_cb,
+    );
+    let item_cb = use_callback({
+        let items = items.clone();
+        let bevy_type = bevy_type.clone();
+        move |data: MutateData| {
+            let mut items = Value::Array(items.clone());
+            let schema = schema.peek();
+
+            if let Some(item) =
+                get_value_at_path_mut(&mut items, &data.path, Some(&bevy_type), &schema)
+            {
+                *item = data.value;
+                submit.call(items);
+            }
+        }
+    });
+    let add = {
+        let items = items.clone();
+        let default_item = default_item.clone();
+
+        move |_| {
+            let mut items = items.clone();
+
+            items.push(default_item.clone());
+            submit.call(items.into());
+        }
+    };
+
+    rsx! {
+        div { class: "value-collection",
+            table { class: "json-value-table json-value-table--array",
+                if items.is_empty() {
+                    div { class: "json-value-empty", "Empty Set" }
+                } else {
+                    for (idx , item) in items.iter().enumerate() {
+                        {
+                            let remove = {
+                                let items = items.clone();
+
+                                move |_| {
+                                    let mut items = items.clone();
+
+                                    items.remove(idx);
+                                    submit.call(items.into());
+                                }
+                            };
+
+                            rsx! {
+                                tr { key: "{item}",
+                                    th { "⚬" }
+                                    td {
+                                        match &item_type {
+                                            Some(item_type) => rsx! {
+                                                ComponentValue {
+                                                    value: item.clone(),
+                                                    component_type: component_type.clone(),
+                                                    bevy_type: item_type.clone(),
+                                                    mutate_cb: item_cb,
+                                                    parent_path: get_array_path(&None, &idx.to_string()),
+                                                }
+                                            },
+                                            None => rsx! {
+                                                div { class: "issue", "Type not found: {bevy_type.items:?}" }
+                                            },
+                                        }
+                                    }
+                                    td { class: "value-collection__actions",
+                                        div {
+                                            class: "value-collection__action",
+                                            title: "Remove item",
+                                            onclick: remove,
+                                            {Icon::Close.render()}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            button {
+                class: "button value-collection__add",
+                // Sets can't hold the default twice, edit the existing one
+                disabled: items.contains(&default_item),
+                onclick: add,
+                {Icon::Add.render()}
+                "Add item"
+            }
+            if let Some(error) = invalid() {
+                div { class: "value-edit__error", "{error}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use client::Value;
+use dioxus::prelude::*;
+
+use crate::{
+    components::component::ComponentValue,
+    states::ConnectionState,
+    utils::{get_object_path, get_type_path_from_ref_value},
+};
+
+use super::MutateData;
+
+/// Editor for tuple structs, tuples and tuple enum variants, one per
+/// `prefix_items` field, mutated through `.0`, `.1`, ...
+///
+/// Single field tuple structs and variants (newtypes) are serialized as
+/// their inner value, set `is_newtype` to edit `value` itself as `.0`.
+#[component]
+pub fn ValueTuple(
+    value: Value,
+    component_type: String,
+    prefix_items: Vec<Value>,
+    is_newtype: bool,
+    mutate_cb: Callback<MutateData>,
+    parent_path: Option<String>,
+) -> Element {
+    let schema = use_context::<ConnectionState>().schema;
+    let fields = prefix_items
+        .iter()
+        .enumerate()
+        .map(|(idx, type_ref)| {
+            let field_type = get_type_path_from_ref_value(type_ref)
+                .and_then(|type_path| schema().get(&type_path).cloned());
+            let field = if is_newtype {
+                Some(value.clone())
+            } else {
+                value.get(idx).cloned()
+            };
+
+            (idx.to_string(), type_ref, field_type, field)
+        })
+        .collect::<Vec<_>>();
+
+    if is_newtype {
+        return match fields.into_iter().next() {
+            Some((idx, _, Some(field_type), Some(field))) => rsx! {
+                ComponentValue {
+                    value: field,
+                    component_type,
+                    bevy_type: field_type,
+                    mutate_cb,
+                    parent_path: get_object_path(&parent_path, &idx),
+                }
+            },
+            _ => rsx! {
+                div { class: "issue", "Type not found: {prefix_items:?}" }
+            },
+        };
+    }
+
+    rsx! {
+        div { class: "json-value-key-list",
+            for (idx , type_ref , field_type , field) in fields {
+                div { key: "{idx}", class: "json-value-key-list__item",
+                    div { class: "json-value-key-list__key", "{idx}" }
+                    div { class: "json-value-key-list__value",
+                        match (field_type, field) {
+                            (Some(field_type), Some(field)) => rsx! {
+                                ComponentValue {
+                                    value: field,
+                                    component_type: component_type.clone(),
+                                    bevy_type: field_type,
+                                    mutate_cb,
+                                    parent_path: get_object_path(&parent_path, &idx),
+                                }
+                            },
+                            (None, _) => rsx! {
+                                div { class: "issue", "Type not found: {type_ref:?}" }
+                            },
+                            (_, None) => rsx! {
+                                div { class: "issue", "Can't unwrap value." }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use client::{JsonSchemaBevyType, SchemaType, Value};
+use dioxus::prelude::*;
+
+use crate::{
+    components::component::{scrub_number::ScrubNumber, validated_submit::use_validated_submit},
+    states::ConnectionState,
+    utils::get_type_path_from_ref_value,
+};
+
+use super::MutateData;
+
+/// Editor for glam vectors (`Vec3`, `IVec2`, `DVec4`, ...), serialized as
+/// `[x, y, ..]` arrays. Editing an axis submits the whole vector.
+#[component]
+pub fn ValueVector(
+    value: Value,
+    component_type: String,
+    bevy_type: JsonSchemaBevyType,
+    mutate_cb: Callback<MutateData>,
+    parent_path: Option<String>,
+) -> Element {
+    let schema = use_context::<ConnectionState>().schema;
+    let axes = ["x", "y", "z", "w"]
+        .into_iter()
+        .filter(|axis| bevy_type.properties.contains_key(*axis))
+        .collect::<Vec<_>>();
+    let scalar_type = bevy_type
+        .properties
+        .get("x")
+        .and_then(get_type_path_from_ref_value)
+        .and_then(|type_path| schema().get(&type_path).cloned());
+
+    let (submit, invalid) = use_validated_submit(
+        component_type,
+        parent_path.unwrap_or_default(),
+        bevy_type.clone(),
+        mutate_cb,
+    );
+
+    let Some(scalar_type) = scalar_type else {
+        return rsx! {
+            div { class: "issue", "Type not found: {bevy_type.properties:?}" }
+        };
+    };
+    let step = match scalar_type.schema_type {
+        SchemaType::Float => 0.01,
+        _ => 0.25,
+    };
+
+    rsx! {
+        div { class: "value-vector",
+            for (idx , axis) in axes.into_iter().enumerate() {
+                ScrubNumber {
+                    key: "{axis}",
+                    label: axis.to_uppercase(),
+                    value: value.get(idx).cloned().unwrap_or_default(),
+                    schema_type: scalar_type.schema_type.clone(),
+                    step,
+                    onchange: {
+                        let vector = value.clone();
+
+                        move |axis_value| {
+                            let mut vector = vector.clone();
+
+                            if let Some(item) = vector.get_mut(idx) {
+                                *item = axis_value;
+                                submit.call(vector);
+                            }
+                        }
+                    },
+                }
+            }
+        }
+        if let Some(error) = invalid() {
+            div { class: "value-edit__error", "{error}" }
+        }
+    }
+}
@@ -1,25 +1,42 @@
-use client::{Entity, EntityItem, EntityKind, SchemaKind, SchemaType};
+use client::{Entity, EntityItem, EntityKind, ErrorCode, SchemaKind, SchemaType, component};
 use dioxus::prelude::*;
 
-use crate::states::{ConnectionState, EntitiesToolState};
+use crate::states::{AuditLogState, ConnectionState, EntitiesToolState, ErrorMessage};
 
-use crate::components::{ComponentInspector, Icon};
+use crate::components::{AddComponent, ComponentInspector, ErrorNotice, Icon};
 use crate::utils::get_short_type_name;
 
 #[component]
 pub fn EntityInspector(id: ReadOnlySignal<Entity>, is_pinned: bool) -> Element {
     let mut pinned = use_context::<EntitiesToolState>().pinned;
-    let active = use_context::<EntitiesToolState>().active;
+    let mut active = use_context::<EntitiesToolState>().active;
     let client = use_context::<ConnectionState>().client;
     let schema = use_context::<ConnectionState>().schema;
     let update_signal = use_context::<ConnectionState>().update_signal;
+    let mut audit_log = use_context::<AuditLogState>();
 
     let mut entity = use_signal(|| None::<EntityItem>);
+    let mut error = use_signal(|| None::<ErrorMessage>);
+    let mut is_watching = use_signal(|| false);
     let update_fn = move || {
         async move {
-            let res = client().get(id()).await;
-            // TODO: Add proper error state
-            entity.set(res.ok());
+            match client().get(id()).await {
+                Ok(res) => {
+                    audit_log.remember_name(id(), res.name());
+                    entity.set(Some(res));
+                    error.set(None);
+                }
+                // Close the inspector when the entity was despawned
+                Err(e) if e.code() == Some(ErrorCode::EntityNotFound) => {
+                    pinned.retain(|&x| x != id());
+
+                    if active() == Some(id()) {
+                        active.set(None);
+                    }
+                }
+                // Keep showing the last known state along with the error
+                Err(e) => error.set(Some(e.into())),
+            }
         }
     };
     let marker_components = use_memo(move || {
@@ -52,15 +69,42 @@ pub fn EntityInspector(id: ReadOnlySignal<Entity>, is_pinned: bool) -> Element {
         }
     );
 
-    // Update data when `id` changes
-    use_effect(move || {
-        id();
-        spawn(update_fn());
+    // Update data when `id` changes, and keep it live through
+    // `world.get_components+watch` if the server supports it. The resource
+    // restarts (and cancels the previous watch) when `id` or the client change.
+    let _watch = use_resource(move || async move {
+        is_watching.set(false);
+
+        // Open the watch before fetching so no change is missed in between
+        let updates = client().watch_entity(id()).await;
+        update_fn().await;
+
+        let Ok(mut updates) = updates else {
+            return;
+        };
+
+        is_watching.set(true);
+
+        // A server without `+watch` methods may only reject them here, as
+        // the first item, which goes back to polling
+        while let Some(Ok(delta)) = updates.next().await {
+            entity.with_mut(|entity| {
+                if let Some(entity) = entity {
+                    entity.apply_delta(delta);
+                }
+            });
+        }
+
+        is_watching.set(false);
     });
 
+    // Fallback to polling when watching isn't available
     use_effect(move || {
         update_signal();
-        spawn(update_fn());
+
+        if !is_watching() {
+            spawn(update_fn());
+        }
     });
 
     match &*entity.read() {
@@ -100,6 +144,10 @@ pub fn EntityInspector(id: ReadOnlySignal<Entity>, is_pinned: bool) -> Element {
                     }
                 }
 
+                if let Some(error) = error() {
+                    ErrorNotice { error, onretry: move |_| { spawn(update_fn()); } }
+                }
+
                 if !marker_components().is_empty() {
                     div { class: "marker-components",
                         for component in marker_components() {
@@ -120,11 +168,35 @@ pub fn EntityInspector(id: ReadOnlySignal<Entity>, is_pinned: bool) -> Element {
                             id: id(),
                             type_path: component.to_string(),
                             value: value.clone(),
+                            global_transform: if component == component::TRANSFORM {
+                                entity.components.get(component::GLOBAL_TRANSFORM).cloned().flatten()
+                            } else {
+                                None
+                            },
                         }
                     }
                 }
+
+                AddComponent {
+                    id: id(),
+                    existing: entity.components.keys().cloned().collect::<Vec<_>>(),
+                }
             }
         },
-        None => rsx! {},
+        None => match error() {
+            Some(error) => rsx! {
+                div { class,
+                    div { class: "inspector-card__header-wrapper",
+                        div { class: "inspector-card__header",
+                            div { class: "inspector-card__icon", {Icon::Diamond.render()} }
+                            span { class: "inspector-card__name inspector-card__name--placeholder", "Entity" }
+                            span { class: "inspector-card__id", "{id}" }
+                        }
+                    }
+                    ErrorNotice { error, onretry: move |_| { spawn(update_fn()); } }
+                }
+            },
+            None => rsx! {},
+        },
     }
 }
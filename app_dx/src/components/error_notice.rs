@@ -0,0 +1,25 @@
+use dioxus::prelude::*;
+
+use crate::{components::Icon, states::ErrorMessage};
+
+/// Inline error of a panel that failed to load, with a retry button.
+#[component]
+pub fn ErrorNotice(error: ErrorMessage, onretry: EventHandler<()>) -> Element {
+    rsx! {
+        div { class: "error-notice",
+            div { class: "error-notice__icon", {Icon::Error.render()} }
+            div { class: "error-notice__message",
+                "{error.message}"
+                if let Some(code) = error.code {
+                    span { class: "error-notice__code", "{code:?} ({code})" }
+                }
+            }
+            button {
+                class: "button error-notice__retry",
+                title: "Retry",
+                onclick: move |_| onretry.call(()),
+                {Icon::Refresh.render()}
+            }
+        }
+    }
+}
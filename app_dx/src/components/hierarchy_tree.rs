@@ -1,10 +1,10 @@
 use client::{Entity, EntityItem, EntityKind};
-use dioxus::{logger::tracing::info, prelude::*};
+use dioxus::prelude::*;
 use std::collections::BTreeMap;
 
 use crate::{
-    components::Icon,
-    states::{ConnectionState, EntitiesToolState},
+    components::{ErrorNotice, Icon},
+    states::{ConnectionState, EntitiesToolState, ErrorMessage},
 };
 
 struct HierarchyItem {
@@ -35,6 +35,34 @@ pub fn HierarchyTree(parent_id: Option<Entity>, level: u32) -> Element {
     );
 
     let mut items: Signal<BTreeMap<Entity, HierarchyItem>> = use_signal(BTreeMap::new);
+    let mut error = use_signal(|| None::<ErrorMessage>);
+    let update_fn = move || async move {
+        let children = client().get_children(parent_id).await;
+
+        match children {
+            Ok(children) => {
+                let old_items = items.take();
+
+                items.set(
+                    children
+                        .into_iter()
+                        .map(|entity| {
+                            let expanded = old_items
+                                .get(&entity.id)
+                                .map(|item| item.expanded)
+                                .unwrap_or(false);
+
+                            (entity.id, HierarchyItem { entity, expanded })
+                        })
+                        .collect::<_>(),
+                );
+                error.set(None);
+            }
+            Err(e) => {
+                error.set(Some(e.into()));
+            }
+        }
+    };
 
     let row_click = |id: Entity| {
         move |_: Event<MouseData>| {
@@ -54,37 +82,20 @@ pub fn HierarchyTree(parent_id: Option<Entity>, level: u32) -> Element {
 
     use_effect(move || {
         update_signal();
-
-        spawn(async move {
-            let children = client().get_children(parent_id).await;
-
-            match children {
-                Ok(children) => {
-                    let old_items = items.take();
-
-                    items.set(
-                        children
-                            .into_iter()
-                            .map(|entity| {
-                                let expanded = old_items
-                                    .get(&entity.id)
-                                    .map(|item| item.expanded)
-                                    .unwrap_or(false);
-
-                                (entity.id, HierarchyItem { entity, expanded })
-                            })
-                            .collect::<_>(),
-                    );
-                }
-                Err(e) => {
-                    info!("Error fetching children: {}", e);
-                }
-            }
-        });
+        spawn(update_fn());
     });
 
     rsx! {
         div { class: item_tree_class, style: "--item-tree-level: {level}",
+            if let Some(error) = error() {
+                ErrorNotice {
+                    error,
+                    onretry: move |_| {
+                        spawn(update_fn());
+                    },
+                }
+            }
+
             for (entity_id , item) in items.read().iter() {
                 div {
                     key: "{entity_id}",
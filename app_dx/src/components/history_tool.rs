@@ -0,0 +1,144 @@
+use client::to_string_pretty;
+use dioxus::prelude::*;
+
+use crate::{
+    components::{Icon, JsonValue},
+    states::{AuditLogState, HistoryEntry, HistoryState, MutationTarget},
+    utils::get_short_type_name,
+};
+
+#[component]
+pub fn HistoryTool() -> Element {
+    let mut history = use_context::<HistoryState>();
+    let undo = history.undo;
+    let redo = history.redo;
+
+    rsx! {
+        div { class: "history",
+            div { class: "inspector-card",
+                div { class: "inspector-card__header-wrapper",
+                    div { class: "inspector-card__header",
+                        div { class: "inspector-card__icon", {Icon::History.render()} }
+                        span { class: "inspector-card__name", "History" }
+                        button {
+                            class: "button history__action",
+                            title: "Undo (Ctrl+Z)",
+                            disabled: undo.read().is_empty(),
+                            onclick: move |_| history.undo(),
+                            {Icon::Undo.render()}
+                        }
+                        button {
+                            class: "button history__action",
+                            title: "Redo (Ctrl+Shift+Z)",
+                            disabled: redo.read().is_empty(),
+                            onclick: move |_| history.redo(),
+                            {Icon::Redo.render()}
+                        }
+                    }
+                }
+
+                if undo.read().is_empty() && redo.read().is_empty() {
+                    div { class: "json-value-empty", "No edits yet." }
+                } else {
+                    table { class: "json-value-table history__table",
+                        tr {
+                            th { "Target" }
+                            th { "Component" }
+                            th { "Path" }
+                            th { "Old" }
+                            th { "New" }
+                        }
+                        // Newest first, undone edits on top
+                        for entry in redo.read().iter() {
+                            HistoryRow { entry: entry.clone(), is_undone: true }
+                        }
+                        for entry in undo.read().iter().rev() {
+                            HistoryRow { entry: entry.clone(), is_undone: false }
+                        }
+                    }
+                }
+            }
+
+            AuditLog {}
+        }
+    }
+}
+
+/// Session log of the applied changes, exported as a script that
+/// `BrpClient::replay` can re-apply to another game instance.
+#[component]
+fn AuditLog() -> Element {
+    let mut audit_log = use_context::<AuditLogState>();
+    let steps = audit_log.steps;
+    let script = use_memo(move || {
+        steps.read();
+        to_string_pretty(&audit_log.script()).unwrap_or_default()
+    });
+    let copy = move |_| {
+        let clipboard = document::eval(
+            r#"
+            const script = await dioxus.recv();
+            await navigator.clipboard.writeText(script);
+            "#,
+        );
+        let _ = clipboard.send(script());
+    };
+
+    rsx! {
+        div { class: "inspector-card",
+            div { class: "inspector-card__header-wrapper",
+                div { class: "inspector-card__header",
+                    div { class: "inspector-card__icon", {Icon::BookShelf.render()} }
+                    span { class: "inspector-card__name", "Session Log" }
+                    span { class: "inspector-card__id", "{steps.read().len()} steps" }
+                    button {
+                        class: "button history__action",
+                        title: "Copy as a replayable script",
+                        disabled: steps.read().is_empty(),
+                        onclick: copy,
+                        "Copy Script"
+                    }
+                    button {
+                        class: "button history__action",
+                        title: "Clear log",
+                        disabled: steps.read().is_empty(),
+                        onclick: move |_| audit_log.clear(),
+                        {Icon::Delete.render()}
+                    }
+                }
+            }
+
+            if steps.read().is_empty() {
+                div { class: "json-value-empty", "No changes yet." }
+            } else {
+                textarea {
+                    class: "history__script",
+                    readonly: true,
+                    value: script(),
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn HistoryRow(entry: HistoryEntry, is_undone: bool) -> Element {
+    let (target, type_path) = match &entry.target {
+        MutationTarget::Component(id, type_path) => (id.to_string(), type_path),
+        MutationTarget::Resource(type_path) => ("Resource".to_string(), type_path),
+    };
+
+    rsx! {
+        tr { class: if is_undone { "history__row history__row--undone" } else { "history__row" },
+            td { class: "history__target", "{target}" }
+            td { title: "{type_path}", {get_short_type_name(type_path)} }
+            td { class: "history__path", "{entry.path}" }
+            td {
+                JsonValue { value: entry.old.clone() }
+            }
+            td {
+                JsonValue { value: entry.new.clone() }
+            }
+        }
+    }
+}
@@ -3,23 +3,35 @@ use dioxus::prelude::*;
 
 #[allow(dead_code)]
 pub enum Icon {
+    Add,
+    ArrowDown,
+    ArrowUp,
     BookShelf,
     Box,
     Bubbles,
     ChevronDown,
     ChevronRight,
+    Close,
     Computer,
     Cursor,
+    Delete,
     Diamond,
+    Error,
     Focus,
+    History,
     Lambda,
     Lightbulb,
+    Lock,
     NodeTree,
     Pin,
     Rectangle,
+    Redo,
     Refresh,
+    Search,
     Squares,
     TextSize,
+    Undo,
+    Unlock,
     Unpin,
     Video,
     Window,
@@ -29,23 +41,35 @@ pub enum Icon {
 impl Icon {
     fn get_file_name(&self) -> (String, f32) {
         let (file_name, ratio) = match self {
+            Icon::Add => ("add-line", 1.0),
+            Icon::ArrowDown => ("arrow-down-line", 1.0),
+            Icon::ArrowUp => ("arrow-up-line", 1.0),
             Icon::BookShelf => ("book-shelf-line", 1.0),
             Icon::Box => ("box-3-line", 1.0),
             Icon::Bubbles => ("bubble-chart-line", 1.0),
             Icon::ChevronDown => ("arrow-down-s-line", 1.0),
             Icon::ChevronRight => ("arrow-right-s-line", 1.0),
+            Icon::Close => ("close-line", 1.0),
             Icon::Computer => ("computer-line", 1.0),
             Icon::Cursor => ("cursor-line", 1.0),
+            Icon::Delete => ("delete-bin-line", 1.0),
             Icon::Diamond => ("poker-diamonds-line", 1.0),
+            Icon::Error => ("error-warning-line", 1.0),
             Icon::Focus => ("focus-2-fill", 1.0),
+            Icon::History => ("history-line", 1.0),
             Icon::Lambda => ("custom-lambda", 1.0),
             Icon::Lightbulb => ("lightbulb-line", 1.0),
+            Icon::Lock => ("lock-line", 1.0),
             Icon::NodeTree => ("node-tree", 1.0),
             Icon::Pin => ("pushpin-line", 1.0),
+            Icon::Redo => ("arrow-go-forward-line", 1.0),
             Icon::Refresh => ("refresh-right-fill", 1.0),
+            Icon::Search => ("search-line", 1.0),
             Icon::Squares => ("function-line", 1.0),
             Icon::TextSize => ("font-size-2", 1.0),
             Icon::Rectangle => ("rectangle-line", 1.0),
+            Icon::Undo => ("arrow-go-back-line", 1.0),
+            Icon::Unlock => ("lock-unlock-line", 1.0),
             Icon::Unpin => ("unpin-line", 1.0),
             Icon::Video => ("video-on-line", 1.0),
             Icon::Window => ("window-fill", 1.0),
@@ -2,20 +2,28 @@ mod component;
 mod connection;
 mod entities_tool;
 mod entity_inspector;
+mod error_notice;
 mod hierarchy_tree;
+mod history_tool;
 mod icon;
 mod json_value;
+mod query_tool;
 mod resource_inspector;
 mod resources_tool;
+mod toasts;
 mod types_tool;
 
 pub use component::*;
 pub use connection::*;
 pub use entities_tool::*;
 pub use entity_inspector::*;
+pub use error_notice::*;
 pub use hierarchy_tree::*;
+pub use history_tool::*;
 pub use icon::*;
 pub use json_value::*;
+pub use query_tool::*;
 pub use resource_inspector::*;
 pub use resources_tool::*;
+pub use toasts::*;
 pub use types_tool::*;
@@ -0,0 +1,171 @@
+use client::{BrpQuery, Entity, component};
+use dioxus::prelude::*;
+
+use crate::{
+    components::{Icon, JsonValue},
+    states::{ConnectionState, QueryToolState},
+    utils::{get_component_types, get_short_type_name},
+};
+
+#[component]
+pub fn QueryTool(onopen: EventHandler<Entity>) -> Element {
+    let client = use_context::<ConnectionState>().client;
+    let option = use_context::<QueryToolState>().option;
+    let with = use_context::<QueryToolState>().with;
+    let without = use_context::<QueryToolState>().without;
+    let mut results = use_context::<QueryToolState>().results;
+
+    let run = move |_| {
+        // Always fetch `Name` so results can be labeled
+        let query = BrpQuery::new()
+            .option([component::NAME])
+            .option(option())
+            .with(with())
+            .without(without());
+
+        spawn(async move {
+            let res = client().query(&query).await;
+            results.set(Some(res.map_err(|e| e.to_string())));
+        });
+    };
+
+    rsx! {
+        div { class: "sidebar-layout",
+            div { class: "sidebar-layout__sidebar query-form",
+                QueryComponents { label: "Option", components: option }
+                QueryComponents { label: "With", components: with }
+                QueryComponents { label: "Without", components: without }
+
+                button { class: "button query-form__run", onclick: run,
+                    {Icon::Search.render()}
+                    "Run Query"
+                }
+            }
+
+            div { class: "sidebar-layout__content",
+                {
+                    match &*results.read() {
+                        Some(Ok(entities)) => rsx! {
+                            div { class: "inspector-card",
+                                div { class: "inspector-card__header-wrapper",
+                                    div { class: "inspector-card__header",
+                                        div { class: "inspector-card__icon", {Icon::Search.render()} }
+                                        span { class: "inspector-card__name", "Results" }
+                                        span { class: "inspector-card__id", "{entities.len()} entities" }
+                                    }
+                                }
+                                if entities.is_empty() {
+                                    div { class: "json-value-empty", "No entities match the query." }
+                                } else {
+                                    table { class: "json-value-table query-results",
+                                        tr {
+                                            th { "Entity" }
+                                            for component in option() {
+                                                th { key: "{component}", title: "{component}",
+                                                    {get_short_type_name(&component)}
+                                                }
+                                            }
+                                        }
+                                        for entity in entities.iter() {
+                                            {
+                                                let id = entity.id;
+                                                rsx! {
+                                                    tr {
+                                                        key: "{id}",
+                                                        class: "query-results__row",
+                                                        onclick: move |_| onopen.call(id),
+                                                        th {
+                                                            match entity.name() {
+                                                                Some(name) => rsx! {
+                                                                    span { "{name} " }
+                                                                },
+                                                                None => rsx! {},
+                                                            }
+                                                            span { class: "query-results__id", "{id}" }
+                                                        }
+                                                        for component in option() {
+                                                            td { key: "{component}",
+                                                                match entity.components.get(&component) {
+                                                                    Some(Some(value)) => rsx! {
+                                                                        JsonValue { value: value.clone() }
+                                                                    },
+                                                                    Some(None) => rsx! {
+                                                                        div { class: "issue issue--no-value", "No value." }
+                                                                    },
+                                                                    None => rsx! {
+                                                                        div { class: "json-value-empty", "—" }
+                                                                    },
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        Some(Err(error)) => rsx! {
+                            div { class: "issue", "{error}" }
+                        },
+                        None => rsx! {},
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn QueryComponents(label: String, components: Signal<Vec<String>>) -> Element {
+    let schema = use_context::<ConnectionState>().schema;
+    let available = get_component_types(&schema.read())
+        .into_iter()
+        .filter(|(type_path, _)| !components.read().contains(type_path))
+        .collect::<Vec<_>>();
+
+    rsx! {
+        div { class: "query-form__section",
+            div { class: "query-form__label", "{label}" }
+
+            if !components().is_empty() {
+                div { class: "marker-components",
+                    for component in components() {
+                        div {
+                            key: "{component}",
+                            class: "marker-components__item query-form__chip",
+                            title: "{component}",
+                            onclick: {
+                                let component = component.clone();
+                                move |_| components.retain(|c| *c != component)
+                            },
+                            {get_short_type_name(&component)}
+                            " ✕"
+                        }
+                    }
+                }
+            }
+
+            div { class: "select",
+                select {
+                    onchange: move |e| {
+                        let type_path = e.value();
+                        if !type_path.is_empty() {
+                            components.push(type_path);
+                        }
+                    },
+                    option { value: "", selected: true, "Add component…" }
+                    for (type_path , short_path) in available {
+                        option {
+                            key: "{type_path}",
+                            value: "{type_path}",
+                            title: "{type_path}",
+                            "{short_path}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,23 +1,37 @@
-use client::Value;
+use client::{ErrorCode, Value};
 use dioxus::prelude::*;
 
-use crate::components::{Icon, JsonValue};
-use crate::states::ConnectionState;
+use crate::components::{ComponentValue, ErrorNotice, Icon, JsonValue, use_inspector_edits};
+use crate::states::{ConnectionState, ErrorMessage, MutationTarget, ResourcesToolState};
 use crate::utils::get_short_type_name;
 
 #[component]
 pub fn ResourceInspector(resource_type: ReadOnlySignal<String>) -> Element {
     let client = use_context::<ConnectionState>().client;
+    let schema = use_context::<ConnectionState>().schema;
     let update_signal = use_context::<ConnectionState>().update_signal;
+    let mut active = use_context::<ResourcesToolState>().active;
     let mut resource = use_signal(|| None::<Value>);
+    let mut error = use_signal(|| None::<ErrorMessage>);
     let update_fn = move || {
         async move {
-            let res = client().get_resource(resource_type()).await;
-            // TODO: Add proper error state
-            resource.set(res.ok());
+            match client().get_resource(resource_type()).await {
+                Ok(res) => {
+                    resource.set(Some(res));
+                    error.set(None);
+                }
+                // Close the inspector when the resource was removed
+                Err(e) if e.code() == Some(ErrorCode::ResourceNotPresent) => active.set(None),
+                Err(e) => error.set(Some(e.into())),
+            }
         }
     };
 
+    let bevy_type = use_memo(move || schema().get(&resource_type()).cloned());
+    let (edits, mutate_cb) = use_inspector_edits(resource.into(), bevy_type, |type_path| {
+        MutationTarget::Resource(type_path.to_string())
+    });
+
     // Update data when `resource_type` changes
     use_effect(move || {
         resource_type();
@@ -42,12 +56,29 @@ pub fn ResourceInspector(resource_type: ReadOnlySignal<String>) -> Element {
                     }
                 }
             }
+            if let Some(error) = error() {
+                ErrorNotice {
+                    error,
+                    onretry: move |_| {
+                        spawn(update_fn());
+                    },
+                }
+            }
             {
-                match &*resource.read() {
-                    Some(resource) => rsx! {
+                match (&*resource.read(), bevy_type()) {
+                    (Some(resource), Some(bevy_type)) => rsx! {
+                        ComponentValue {
+                            value: edits.apply(resource.clone(), Some(&bevy_type), &schema.read()),
+                            component_type: bevy_type.type_path.clone(),
+                            bevy_type,
+                            mutate_cb,
+                        }
+                    },
+                    // Not in the registry, so there's no schema to edit it
+                    (Some(resource), None) => rsx! {
                         JsonValue { value: resource.clone() }
                     },
-                    None => rsx! {},
+                    (None, _) => rsx! {},
                 }
             }
         }
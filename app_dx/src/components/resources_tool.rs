@@ -1,8 +1,8 @@
 use dioxus::prelude::*;
 
 use crate::{
-    components::ResourceInspector,
-    states::{ConnectionState, ResourcesToolState},
+    components::{ErrorNotice, ResourceInspector},
+    states::{ConnectionState, ErrorMessage, ResourcesToolState},
     utils::{add_zero_width_spaces, get_short_type_name},
 };
 
@@ -12,32 +12,44 @@ pub fn ResourcesTool() -> Element {
     let update_signal = use_context::<ConnectionState>().update_signal;
     let mut active = use_context::<ResourcesToolState>().active;
     let mut resources = use_signal(Vec::<String>::new);
+    let mut error = use_signal(|| None::<ErrorMessage>);
     let row_click = |ty: String| {
         move |_| {
             active.set(Some(ty.clone()));
         }
     };
 
-    use_effect(move || {
-        update_signal();
+    let update_fn = move || async move {
+        let res = client().list_resources().await;
 
-        spawn(async move {
-            let res = client().list_resources().await;
-
-            match res {
-                Ok(res) => {
-                    resources.set(res);
-                }
-                Err(_) => {
-                    resources.set(Vec::new());
-                }
+        match res {
+            Ok(res) => {
+                resources.set(res);
+                error.set(None);
+            }
+            Err(e) => {
+                resources.set(Vec::new());
+                error.set(Some(e.into()));
             }
-        });
+        }
+    };
+
+    use_effect(move || {
+        update_signal();
+        spawn(update_fn());
     });
 
     rsx! {
         div { class: "sidebar-layout",
             div { class: "sidebar-layout__sidebar",
+                if let Some(error) = error() {
+                    ErrorNotice {
+                        error,
+                        onretry: move |_| {
+                            spawn(update_fn());
+                        },
+                    }
+                }
                 div { class: "item-tree item-tree--root item-tree--flat",
                     for res in resources.iter() {
                         div {
@@ -0,0 +1,36 @@
+use dioxus::prelude::*;
+
+use crate::{components::Icon, states::NotificationsState};
+
+#[component]
+pub fn Toasts() -> Element {
+    let mut notifications = use_context::<NotificationsState>();
+
+    rsx! {
+        div { class: "toasts",
+            for toast in notifications.toasts.iter() {
+                div { key: "{toast.id}", class: "toast",
+                    div { class: "toast__icon", {Icon::Error.render()} }
+                    div { class: "toast__content",
+                        div { class: "toast__title", "{toast.title}" }
+                        div { class: "toast__message",
+                            "{toast.error.message}"
+                            if let Some(code) = toast.error.code {
+                                span { class: "toast__code", "{code:?} ({code})" }
+                            }
+                        }
+                    }
+                    div {
+                        class: "toast__close",
+                        title: "Dismiss",
+                        onclick: {
+                            let id = toast.id;
+                            move |_| notifications.dismiss(id)
+                        },
+                        {Icon::Close.render()}
+                    }
+                }
+            }
+        }
+    }
+}
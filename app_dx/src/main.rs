@@ -6,11 +6,19 @@ use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitEx
 #[cfg(not(target_arch = "wasm32"))]
 use dioxus::desktop::{Config, LogicalSize, WindowBuilder};
 
-use components::{Connection, EntitiesTool, Icon, ResourcesTool, TypesTool};
-use states::{ConnectionState, EntitiesToolState, ResourcesToolState, TypesToolState};
+use components::{
+    Connection, EntitiesTool, HistoryTool, Icon, QueryTool, ResourcesTool, Toasts, TypesTool,
+};
+use states::{
+    AuditLogState, ConnectionState, EntitiesToolState, HistoryState, MutationQueueState,
+    NotificationsState, QueryToolState, ResourcesToolState, TypesToolState,
+};
 
 mod bevy_type;
+mod color;
 mod components;
+mod math;
+mod numeric;
 mod states;
 mod utils;
 
@@ -46,18 +54,54 @@ fn main() {
 #[derive(PartialEq)]
 enum Tool {
     Entities,
+    History,
+    Query,
     Resources,
     Types,
 }
 
 #[component]
 fn App() -> Element {
-    use_context_provider(|| ConnectionState::new("http://127.0.0.1:15702"));
-    use_context_provider(EntitiesToolState::new);
+    let connection = use_context_provider(|| ConnectionState::new("http://127.0.0.1:15702"));
+    let audit_log = use_context_provider(AuditLogState::new);
+    let mutation_queue =
+        use_context_provider(|| MutationQueueState::new(connection.client, audit_log));
+    let notifications = use_context_provider(NotificationsState::new);
+    let mut history = use_context_provider(|| {
+        HistoryState::new(mutation_queue, notifications, connection.update_signal)
+    });
+    let mut active_entity = use_context_provider(EntitiesToolState::new).active;
+    use_context_provider(QueryToolState::new);
     use_context_provider(ResourcesToolState::new);
     use_context_provider(TypesToolState::new);
 
     let mut tool = use_signal(|| Tool::Entities);
+
+    // Undo/redo shortcuts, listened on the document so they work wherever
+    // the focus is, except in editable fields, which have their own undo
+    use_future(move || async move {
+        let mut shortcuts = document::eval(
+            r#"
+            const isEditable = (target) =>
+                ["INPUT", "SELECT", "TEXTAREA"].includes(target.tagName) || target.isContentEditable;
+
+            document.addEventListener("keydown", (e) => {
+                if ((e.ctrlKey || e.metaKey) && e.key.toLowerCase() === "z" && !isEditable(e.target)) {
+                    e.preventDefault();
+                    dioxus.send(e.shiftKey ? "redo" : "undo");
+                }
+            });
+            "#,
+        );
+
+        while let Ok(action) = shortcuts.recv::<String>().await {
+            match action.as_str() {
+                "undo" => history.undo(),
+                "redo" => history.redo(),
+                _ => {}
+            }
+        }
+    });
     let tab_class = |tab: &Tool| {
         if *tool.read() == *tab {
             "tabs__item tabs__item--active"
@@ -87,12 +131,24 @@ fn App() -> Element {
                         {Icon::BookShelf.render()}
                         "Resources"
                     }
+                    div {
+                        class: tab_class(&Tool::Query),
+                        onclick: move |_| tool.set(Tool::Query),
+                        {Icon::Search.render()}
+                        "Query"
+                    }
                     div {
                         class: tab_class(&Tool::Types),
                         onclick: move |_| tool.set(Tool::Types),
                         {Icon::Squares.render()}
                         "Types"
                     }
+                    div {
+                        class: tab_class(&Tool::History),
+                        onclick: move |_| tool.set(Tool::History),
+                        {Icon::History.render()}
+                        "History"
+                    }
                 }
             }
 
@@ -102,15 +158,28 @@ fn App() -> Element {
                         Tool::Entities => rsx! {
                             EntitiesTool {}
                         },
+                        Tool::History => rsx! {
+                            HistoryTool {}
+                        },
                         Tool::Resources => rsx! {
                             ResourcesTool {}
                         },
+                        Tool::Query => rsx! {
+                            QueryTool {
+                                onopen: move |id| {
+                                    active_entity.set(Some(id));
+                                    tool.set(Tool::Entities);
+                                },
+                            }
+                        },
                         Tool::Types => rsx! {
                             TypesTool {}
                         },
                     }
                 }
             }
+
+            Toasts {}
         }
     }
 }
@@ -0,0 +1,400 @@
+//! Quaternion helpers for the rotation editors. Quaternions are `[x, y, z, w]`
+//! as serialized by glam, angles are in degrees, in Bevy's yaw (Y), pitch (X),
+//! roll (Z) order (`EulerRot::YXZ`).
+
+pub type Vec3 = [f64; 3];
+
+pub type Quat = [f64; 4];
+
+pub const QUAT_IDENTITY: Quat = [0.0, 0.0, 0.0, 1.0];
+
+/// `[yaw, pitch, roll]` in degrees
+pub type Euler = [f64; 3];
+
+pub fn quat_mul(a: Quat, b: Quat) -> Quat {
+    let [ax, ay, az, aw] = a;
+    let [bx, by, bz, bw] = b;
+
+    [
+        aw * bx + ax * bw + ay * bz - az * by,
+        aw * by - ax * bz + ay * bw + az * bx,
+        aw * bz + ax * by - ay * bx + az * bw,
+        aw * bw - ax * bx - ay * by - az * bz,
+    ]
+}
+
+/// Falls back to identity for zero length quaternions.
+pub fn quat_normalize(quat: Quat) -> Quat {
+    let length = quat.iter().map(|v| v * v).sum::<f64>().sqrt();
+
+    if length < f64::EPSILON || !length.is_finite() {
+        return QUAT_IDENTITY;
+    }
+
+    quat.map(|v| v / length)
+}
+
+pub fn quat_from_euler([yaw, pitch, roll]: Euler) -> Quat {
+    let (sy, cy) = (yaw.to_radians() / 2.0).sin_cos();
+    let (sp, cp) = (pitch.to_radians() / 2.0).sin_cos();
+    let (sr, cr) = (roll.to_radians() / 2.0).sin_cos();
+
+    let yaw = [0.0, sy, 0.0, cy];
+    let pitch = [sp, 0.0, 0.0, cp];
+    let roll = [0.0, 0.0, sr, cr];
+
+    quat_normalize(quat_mul(quat_mul(yaw, pitch), roll))
+}
+
+pub fn quat_to_euler(quat: Quat) -> Euler {
+    let [x, y, z, w] = quat_normalize(quat);
+
+    // Rotation matrix elements, `m12` is row 1 column 2
+    let m12 = 2.0 * (y * z - w * x);
+    let pitch = (-m12).clamp(-1.0, 1.0).asin();
+
+    let (yaw, roll) = if m12.abs() < 0.999_999 {
+        let m02 = 2.0 * (x * z + w * y);
+        let m22 = 1.0 - 2.0 * (x * x + y * y);
+        let m10 = 2.0 * (x * y + w * z);
+        let m11 = 1.0 - 2.0 * (x * x + z * z);
+
+        (m02.atan2(m22), m10.atan2(m11))
+    } else {
+        // Gimbal lock, yaw and roll rotate around the same axis
+        let m20 = 2.0 * (x * z - w * y);
+        let m00 = 1.0 - 2.0 * (y * y + z * z);
+
+        ((-m20).atan2(m00), 0.0)
+    };
+
+    [yaw.to_degrees(), pitch.to_degrees(), roll.to_degrees()]
+}
+
+/// Whether both represent the same rotation, `q` and `-q` do.
+pub fn quat_approx_eq(a: Quat, b: Quat) -> bool {
+    let dot = a.iter().zip(b).map(|(a, b)| a * b).sum::<f64>();
+
+    (1.0 - dot.abs()) < 1e-6
+}
+
+/// Rotation of an orthonormal basis, the columns of a rotation matrix. Same
+/// as glam's `Quat::from_rotation_axes`.
+pub fn quat_from_axes(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Quat {
+    // `m01` is column 0, row 1
+    let [m00, m01, m02] = x_axis;
+    let [m10, m11, m12] = y_axis;
+    let [m20, m21, m22] = z_axis;
+
+    let quat = if m22 <= 0.0 {
+        let dif10 = m11 - m00;
+        let omm22 = 1.0 - m22;
+
+        if dif10 <= 0.0 {
+            let four_xsq = omm22 - dif10;
+            let inv4x = 0.5 / four_xsq.sqrt();
+
+            [
+                four_xsq * inv4x,
+                (m01 + m10) * inv4x,
+                (m02 + m20) * inv4x,
+                (m12 - m21) * inv4x,
+            ]
+        } else {
+            let four_ysq = omm22 + dif10;
+            let inv4y = 0.5 / four_ysq.sqrt();
+
+            [
+                (m01 + m10) * inv4y,
+                four_ysq * inv4y,
+                (m12 + m21) * inv4y,
+                (m20 - m02) * inv4y,
+            ]
+        }
+    } else {
+        let sum10 = m11 + m00;
+        let opm22 = 1.0 + m22;
+
+        if sum10 <= 0.0 {
+            let four_zsq = opm22 - sum10;
+            let inv4z = 0.5 / four_zsq.sqrt();
+
+            [
+                (m02 + m20) * inv4z,
+                (m12 + m21) * inv4z,
+                four_zsq * inv4z,
+                (m01 - m10) * inv4z,
+            ]
+        } else {
+            let four_wsq = opm22 + sum10;
+            let inv4w = 0.5 / four_wsq.sqrt();
+
+            [
+                (m12 - m21) * inv4w,
+                (m20 - m02) * inv4w,
+                (m01 - m10) * inv4w,
+                four_wsq * inv4w,
+            ]
+        }
+    };
+
+    quat_normalize(quat)
+}
+
+/// Splits an `Affine3A`, serialized by glam as its `x_axis`, `y_axis`,
+/// `z_axis` and `translation` columns, into translation, rotation and scale.
+/// Like glam's `to_scale_rotation_translation`, a mirroring is put in the X
+/// scale. Degenerate (zero scale) matrices get an identity rotation.
+pub fn affine_decompose(affine: [f64; 12]) -> (Vec3, Quat, Vec3) {
+    let column = |idx: usize| [affine[idx * 3], affine[idx * 3 + 1], affine[idx * 3 + 2]];
+    let axes = [column(0), column(1), column(2)];
+    let translation = column(3);
+
+    let length = |axis: Vec3| axis.iter().map(|v| v * v).sum::<f64>().sqrt();
+    let [x_axis, y_axis, z_axis] = axes;
+    let det = x_axis[0] * (y_axis[1] * z_axis[2] - y_axis[2] * z_axis[1])
+        - x_axis[1] * (y_axis[0] * z_axis[2] - y_axis[2] * z_axis[0])
+        + x_axis[2] * (y_axis[0] * z_axis[1] - y_axis[1] * z_axis[0]);
+    let scale = [
+        length(x_axis) * if det < 0.0 { -1.0 } else { 1.0 },
+        length(y_axis),
+        length(z_axis),
+    ];
+
+    if scale
+        .iter()
+        .any(|v| v.abs() < f64::EPSILON || !v.is_finite())
+    {
+        return (translation, QUAT_IDENTITY, scale);
+    }
+
+    let [x_axis, y_axis, z_axis] = [0, 1, 2].map(|idx| axes[idx].map(|v| v / scale[idx]));
+
+    (translation, quat_from_axes(x_axis, y_axis, z_axis), scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_quat_eq(a: Quat, b: Quat) {
+        assert!(quat_approx_eq(a, b), "{a:?} != {b:?}");
+    }
+
+    fn assert_approx_eq(a: &[f64], b: &[f64], epsilon: f64) {
+        assert!(
+            a.iter().zip(b).all(|(a, b)| (a - b).abs() < epsilon),
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn euler_axes() {
+        let half = 45_f64.to_radians().sin();
+
+        // Yaw around Y, pitch around X, roll around Z
+        assert_quat_eq(quat_from_euler([90.0, 0.0, 0.0]), [0.0, half, 0.0, half]);
+        assert_quat_eq(quat_from_euler([0.0, 90.0, 0.0]), [half, 0.0, 0.0, half]);
+        assert_quat_eq(quat_from_euler([0.0, 0.0, 90.0]), [0.0, 0.0, half, half]);
+        assert_quat_eq(quat_from_euler([0.0, 0.0, 0.0]), QUAT_IDENTITY);
+    }
+
+    #[test]
+    fn euler_order_is_yxz() {
+        let yaw = quat_from_euler([30.0, 0.0, 0.0]);
+        let pitch = quat_from_euler([0.0, 40.0, 0.0]);
+        let roll = quat_from_euler([0.0, 0.0, 50.0]);
+
+        assert_quat_eq(
+            quat_from_euler([30.0, 40.0, 50.0]),
+            quat_mul(quat_mul(yaw, pitch), roll),
+        );
+    }
+
+    #[test]
+    fn euler_round_trip() {
+        for euler in [
+            [0.0, 0.0, 0.0],
+            [30.0, 40.0, 50.0],
+            [-120.0, 10.0, 170.0],
+            [179.0, -89.0, -179.0],
+            [45.0, 89.9, 10.0],
+            [-60.0, -89.9, 20.0],
+        ] {
+            assert_approx_eq(&quat_to_euler(quat_from_euler(euler)), &euler, 1e-6);
+        }
+    }
+
+    #[test]
+    fn quat_round_trip() {
+        for quat in [
+            QUAT_IDENTITY,
+            quat_normalize([0.1, 0.2, 0.3, 0.9]),
+            quat_normalize([-0.5, 0.5, -0.5, 0.5]),
+            quat_normalize([0.7, 0.0, 0.0, -0.7]),
+        ] {
+            assert_quat_eq(quat_from_euler(quat_to_euler(quat)), quat);
+        }
+    }
+
+    #[test]
+    fn euler_gimbal_lock() {
+        for pitch in [90.0, -90.0, 89.95, -89.95] {
+            for (yaw, roll) in [(0.0, 0.0), (30.0, 20.0), (-75.0, 140.0)] {
+                let quat = quat_from_euler([yaw, pitch, roll]);
+                let euler = quat_to_euler(quat);
+
+                // At and near ±90° pitch yaw and roll are (almost) the same
+                // axis, everything is put in the yaw but the rotation is kept
+                assert!((euler[1] - pitch).abs() < 1e-3, "{euler:?}");
+                assert_eq!(euler[2], 0.0);
+                assert_quat_eq(quat_from_euler(euler), quat);
+            }
+        }
+    }
+
+    fn rotate(quat: Quat, [x, y, z]: Vec3) -> Vec3 {
+        let [qx, qy, qz, qw] = quat;
+        let [x, y, z, _] = quat_mul(quat_mul(quat, [x, y, z, 0.0]), [-qx, -qy, -qz, qw]);
+
+        [x, y, z]
+    }
+
+    /// Inverse of `affine_decompose`, the columns of `T * R * S`
+    fn compose(translation: Vec3, rotation: Quat, scale: Vec3) -> [f64; 12] {
+        let mut affine = [0.0; 12];
+        let axes = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+        for (idx, axis) in axes.into_iter().enumerate() {
+            let column = rotate(rotation, axis.map(|v| v * scale[idx]));
+
+            affine[idx * 3..idx * 3 + 3].copy_from_slice(&column);
+        }
+
+        affine[9..].copy_from_slice(&translation);
+        affine
+    }
+
+    #[test]
+    fn decompose_identity() {
+        #[rustfmt::skip]
+        let affine = [
+            1.0, 0.0, 0.0,
+            0.0, 1.0, 0.0,
+            0.0, 0.0, 1.0,
+            1.0, 2.0, 3.0,
+        ];
+
+        let (translation, rotation, scale) = affine_decompose(affine);
+
+        assert_eq!(translation, [1.0, 2.0, 3.0]);
+        assert_quat_eq(rotation, QUAT_IDENTITY);
+        assert_eq!(scale, [1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn decompose_non_uniform_scale() {
+        // 90° around Y, scaled by 2, 3, 4
+        #[rustfmt::skip]
+        let affine = [
+            0.0, 0.0, -2.0,
+            0.0, 3.0, 0.0,
+            4.0, 0.0, 0.0,
+            -1.0, 0.5, 0.0,
+        ];
+        let half = 45_f64.to_radians().sin();
+
+        let (translation, rotation, scale) = affine_decompose(affine);
+
+        assert_eq!(translation, [-1.0, 0.5, 0.0]);
+        assert_quat_eq(rotation, [0.0, half, 0.0, half]);
+        assert_approx_eq(&scale, &[2.0, 3.0, 4.0], 1e-12);
+        assert_approx_eq(&quat_to_euler(rotation), &[90.0, 0.0, 0.0], 1e-6);
+    }
+
+    #[test]
+    fn decompose_negative_determinant() {
+        // Mirrored in X
+        #[rustfmt::skip]
+        let affine = [
+            -1.0, 0.0, 0.0,
+            0.0, 1.0, 0.0,
+            0.0, 0.0, 1.0,
+            0.0, 0.0, 0.0,
+        ];
+
+        let (_, rotation, scale) = affine_decompose(affine);
+
+        assert_quat_eq(rotation, QUAT_IDENTITY);
+        assert_eq!(scale, [-1.0, 1.0, 1.0]);
+
+        // Mirrored in Y, the sign moves to X and the rest is a 180° rotation
+        // around Z
+        #[rustfmt::skip]
+        let affine = [
+            1.0, 0.0, 0.0,
+            0.0, -1.0, 0.0,
+            0.0, 0.0, 1.0,
+            0.0, 0.0, 0.0,
+        ];
+
+        let (_, rotation, scale) = affine_decompose(affine);
+
+        assert_quat_eq(rotation, [0.0, 0.0, 1.0, 0.0]);
+        assert_eq!(scale, [-1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn decompose_recomposes() {
+        for (rotation, scale) in [
+            ([30.0, 40.0, 50.0], [0.5, 2.0, 3.0]),
+            ([-120.0, 10.0, 170.0], [1.0, 1.0, -3.0]),
+            ([45.0, 90.0, 0.0], [-2.0, -2.0, -2.0]),
+        ] {
+            let affine = compose([1.0, -2.0, 3.0], quat_from_euler(rotation), scale);
+            let (translation, rotation, scale) = affine_decompose(affine);
+
+            assert_approx_eq(&compose(translation, rotation, scale), &affine, 1e-9);
+            assert!(scale[1] > 0.0 && scale[2] > 0.0, "{scale:?}");
+        }
+    }
+
+    #[test]
+    fn decompose_zero_scale() {
+        #[rustfmt::skip]
+        let affine = [
+            0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0,
+            0.0, 0.0, 1.0,
+            0.0, 0.0, 0.0,
+        ];
+
+        let (_, rotation, scale) = affine_decompose(affine);
+
+        assert_eq!(rotation, QUAT_IDENTITY);
+        assert_eq!(scale, [0.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn quat_from_axes_matches_euler() {
+        for euler in [
+            [0.0, 0.0, 0.0],
+            [30.0, 40.0, 50.0],
+            [180.0, 0.0, 0.0],
+            [0.0, 0.0, 180.0],
+        ] {
+            let quat = quat_from_euler(euler);
+            let [x_axis, y_axis, z_axis] =
+                [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]].map(|axis| rotate(quat, axis));
+
+            assert_quat_eq(quat_from_axes(x_axis, y_axis, z_axis), quat);
+        }
+    }
+
+    #[test]
+    fn quat_normalize_degenerate() {
+        assert_eq!(quat_normalize([0.0; 4]), QUAT_IDENTITY);
+        assert_eq!(quat_normalize([f64::NAN, 0.0, 0.0, 1.0]), QUAT_IDENTITY);
+        assert_approx_eq(&quat_normalize([0.0, 0.0, 0.0, 2.0]), &QUAT_IDENTITY, 1e-12);
+    }
+}
@@ -0,0 +1,198 @@
+use client::{SchemaType, Value, from_str};
+use std::fmt;
+
+/// A number as shown and edited in the inspector. Integers keep their exact
+/// text, so `u64`/`i64` values above 2^53 aren't rounded through `f64`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Numeric {
+    /// Exact integer digits, e.g. `18446744073709551615`
+    Int(String),
+    Float(f64),
+    /// NaN or ±infinity. JSON has no representation for them, serde_json
+    /// writes them as `null`, so which one it was is lost.
+    NonFinite,
+}
+
+impl Numeric {
+    /// Reads a number sent by the server. `null` in a float field is a
+    /// non-finite value.
+    pub fn from_value(value: &Value, schema_type: &SchemaType) -> Option<Self> {
+        match value {
+            Value::Number(number) if number.is_f64() => number.as_f64().map(Self::Float),
+            Value::Number(number) => Some(Self::Int(number.to_string())),
+            Value::Null if *schema_type == SchemaType::Float => Some(Self::NonFinite),
+            _ => None,
+        }
+    }
+
+    /// Parses user input for a field of the given type. Integer fields only
+    /// accept integers, `NaN` and `inf` are recognized but rejected, as they
+    /// can't be sent.
+    pub fn parse(text: &str, schema_type: &SchemaType) -> Result<Self, String> {
+        let text = text.trim();
+
+        match schema_type {
+            SchemaType::Int | SchemaType::Uint => {
+                let digits = match text.strip_prefix('-') {
+                    Some(_) if *schema_type == SchemaType::Uint => {
+                        return Err("expected a non-negative integer".to_string());
+                    }
+                    Some(digits) => digits,
+                    None => text,
+                };
+
+                if digits.is_empty() || !digits.chars().all(|char| char.is_ascii_digit()) {
+                    return Err("expected an integer".to_string());
+                }
+
+                // JSON doesn't allow leading zeros, `007` is sent as `7`
+                let digits = match digits.trim_start_matches('0') {
+                    "" => "0",
+                    digits => digits,
+                };
+
+                if text.starts_with('-') && digits != "0" {
+                    Ok(Self::Int(format!("-{digits}")))
+                } else {
+                    Ok(Self::Int(digits.to_string()))
+                }
+            }
+            _ => match text.parse::<f64>() {
+                Ok(number) if number.is_finite() => Ok(Self::Float(number)),
+                Ok(_) => Err("NaN and infinity can't be sent over BRP".to_string()),
+                Err(_) => Err("expected a number".to_string()),
+            },
+        }
+    }
+
+    /// JSON number to send, integers as JSON integers.
+    pub fn to_value(&self) -> Result<Value, String> {
+        match self {
+            Self::Int(text) => match from_str::<Value>(text) {
+                Ok(value) if value.is_i64() || value.is_u64() => Ok(value),
+                _ => Err(format!("{text} is out of the 64-bit range")),
+            },
+            Self::Float(number) => Ok(Value::from(*number)),
+            Self::NonFinite => Err("NaN and infinity can't be sent over BRP".to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Numeric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(text) => write!(f, "{text}"),
+            Self::Float(number) => write!(f, "{number}"),
+            Self::NonFinite => write!(f, "NaN/∞"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use client::json;
+
+    #[test]
+    fn integers_above_2_53_are_exact() {
+        let max = json!(u64::MAX);
+        let number = Numeric::from_value(&max, &SchemaType::Uint).unwrap();
+
+        assert_eq!(number, Numeric::Int("18446744073709551615".to_string()));
+        assert_eq!(number.to_string(), "18446744073709551615");
+        assert_eq!(number.to_value(), Ok(max));
+
+        // 2^53 + 1 isn't representable as `f64`
+        let number = Numeric::parse("9007199254740993", &SchemaType::Int).unwrap();
+
+        assert_eq!(number.to_value(), Ok(json!(9_007_199_254_740_993_i64)));
+
+        let number = Numeric::parse("-9223372036854775808", &SchemaType::Int).unwrap();
+
+        assert_eq!(number.to_value(), Ok(json!(i64::MIN)));
+    }
+
+    #[test]
+    fn integers_out_of_range_are_rejected() {
+        let number = Numeric::parse("18446744073709551616", &SchemaType::Uint).unwrap();
+
+        assert!(number.to_value().is_err());
+
+        let number = Numeric::parse("-9223372036854775809", &SchemaType::Int).unwrap();
+
+        assert!(number.to_value().is_err());
+    }
+
+    #[test]
+    fn negative_unsigned_input_is_rejected() {
+        assert!(Numeric::parse("-1", &SchemaType::Uint).is_err());
+        assert!(Numeric::parse("-0", &SchemaType::Uint).is_err());
+        assert_eq!(
+            Numeric::parse("-1", &SchemaType::Int),
+            Ok(Numeric::Int("-1".to_string()))
+        );
+    }
+
+    #[test]
+    fn leading_zeros_are_dropped() {
+        for (text, expected) in [("007", "7"), ("-007", "-7"), ("000", "0"), ("-0", "0")] {
+            assert_eq!(
+                Numeric::parse(text, &SchemaType::Int),
+                Ok(Numeric::Int(expected.to_string())),
+                "{text}"
+            );
+        }
+
+        let number = Numeric::parse("007", &SchemaType::Uint).unwrap();
+
+        assert_eq!(number.to_value(), Ok(json!(7)));
+    }
+
+    #[test]
+    fn integer_fields_only_accept_integers() {
+        for text in ["", "-", "1.5", "1e3", "0x10", "one"] {
+            assert!(Numeric::parse(text, &SchemaType::Int).is_err(), "{text}");
+        }
+
+        assert_eq!(
+            Numeric::parse(" 42 ", &SchemaType::Uint),
+            Ok(Numeric::Int("42".to_string()))
+        );
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!(
+            Numeric::parse("1.5", &SchemaType::Float),
+            Ok(Numeric::Float(1.5))
+        );
+        assert_eq!(
+            Numeric::parse("-2e3", &SchemaType::Float),
+            Ok(Numeric::Float(-2000.0))
+        );
+        assert_eq!(Numeric::Float(0.25).to_value(), Ok(json!(0.25)));
+        assert!(Numeric::parse("one", &SchemaType::Float).is_err());
+    }
+
+    #[test]
+    fn nan_and_infinity_are_rejected() {
+        for text in ["NaN", "nan", "inf", "-inf", "infinity", "1e400"] {
+            assert!(Numeric::parse(text, &SchemaType::Float).is_err(), "{text}");
+        }
+
+        assert!(Numeric::NonFinite.to_value().is_err());
+    }
+
+    #[test]
+    fn null_is_non_finite_only_in_float_fields() {
+        assert_eq!(
+            Numeric::from_value(&Value::Null, &SchemaType::Float),
+            Some(Numeric::NonFinite)
+        );
+        assert_eq!(Numeric::from_value(&Value::Null, &SchemaType::Int), None);
+        assert_eq!(
+            Numeric::from_value(&json!(1.5), &SchemaType::Float),
+            Some(Numeric::Float(1.5))
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use client::{Entity, EntityRef, Script, ScriptStep};
+use dioxus::prelude::*;
+use std::collections::BTreeMap;
+
+/// Session log of the changes made to the world, exportable as a `Script`
+/// to re-apply them to another game instance.
+#[derive(Clone, Copy)]
+pub struct AuditLogState {
+    /// Last known `Name` of the inspected entities, so steps can reference
+    /// them by name
+    names: Signal<BTreeMap<Entity, String>>,
+    pub steps: Signal<Vec<ScriptStep>>,
+}
+
+impl AuditLogState {
+    pub fn new() -> Self {
+        Self {
+            names: Signal::new(BTreeMap::new()),
+            steps: Signal::new(vec![]),
+        }
+    }
+
+    pub fn remember_name(&mut self, id: Entity, name: Option<String>) {
+        if self.names.peek().get(&id) == name.as_ref() {
+            return;
+        }
+
+        match name {
+            Some(name) => self.names.write().insert(id, name),
+            None => self.names.write().remove(&id),
+        };
+    }
+
+    pub fn entity_ref(&self, id: Entity) -> EntityRef {
+        EntityRef::new(id, self.names.peek().get(&id).cloned())
+    }
+
+    pub fn record(&mut self, step: ScriptStep) {
+        self.steps.push(step);
+    }
+
+    pub fn clear(&mut self) {
+        self.steps.clear();
+    }
+
+    pub fn script(&self) -> Script {
+        Script {
+            steps: self.steps.read().clone(),
+        }
+    }
+}
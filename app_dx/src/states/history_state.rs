@@ -0,0 +1,176 @@
+use client::Value;
+use dioxus::prelude::*;
+use std::time::Duration;
+
+use crate::{
+    states::{MutationQueueState, MutationTarget, NotificationsState},
+    utils::{get_short_type_name, sleep},
+};
+
+/// Edits of the same field closer than this are merged into one entry.
+const MERGE_WINDOW: Duration = Duration::from_millis(500);
+
+/// A mutation along with the value it replaced.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    pub target: MutationTarget,
+    pub path: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+impl HistoryEntry {
+    fn describe(&self) -> String {
+        let type_path = match &self.target {
+            MutationTarget::Component(_, type_path) | MutationTarget::Resource(type_path) => {
+                type_path
+            }
+        };
+
+        format!("{}{}", get_short_type_name(type_path), self.path)
+    }
+}
+
+/// Undo/redo stacks of the edits made through the inspectors.
+#[derive(Clone, Copy)]
+pub struct HistoryState {
+    mutation_queue: MutationQueueState,
+    notifications: NotificationsState,
+    pub redo: Signal<Vec<HistoryEntry>>,
+    pub undo: Signal<Vec<HistoryEntry>>,
+    update_signal: Signal<()>,
+    /// Bumped on every record, undo and redo, so a stale window timer
+    /// doesn't close a newer window
+    generation: Signal<u32>,
+    is_mergeable: Signal<bool>,
+}
+
+impl HistoryState {
+    pub fn new(
+        mutation_queue: MutationQueueState,
+        notifications: NotificationsState,
+        update_signal: Signal<()>,
+    ) -> Self {
+        Self {
+            mutation_queue,
+            notifications,
+            redo: Signal::new(vec![]),
+            undo: Signal::new(vec![]),
+            update_signal,
+            generation: Signal::new(0),
+            is_mergeable: Signal::new(false),
+        }
+    }
+
+    /// Records an edit accepted by the server. Consecutive edits of the same
+    /// field in a short time (e.g. typing a number) are merged into a single
+    /// entry.
+    pub fn record(&mut self, target: MutationTarget, path: String, old: Value, new: Value) {
+        self.redo.clear();
+
+        let is_mergeable = *self.is_mergeable.peek();
+        let mut undo = self.undo.write();
+
+        match undo.last_mut() {
+            Some(last) if is_mergeable && last.target == target && last.path == path => {
+                last.new = new;
+            }
+            _ => undo.push(HistoryEntry {
+                target,
+                path,
+                old,
+                new,
+            }),
+        }
+
+        drop(undo);
+        self.open_merge_window();
+    }
+
+    /// Reverts the last edit. The entry moves to the redo stack once the
+    /// server accepts it, and stays undoable if it fails.
+    pub fn undo(&mut self) {
+        let Some(entry) = self.undo.pop() else {
+            return;
+        };
+
+        self.close_merge_window();
+        self.apply(entry, Action::Undo);
+    }
+
+    /// Reapplies the last undone edit, see [`Self::undo`].
+    pub fn redo(&mut self) {
+        let Some(entry) = self.redo.pop() else {
+            return;
+        };
+
+        self.close_merge_window();
+        self.apply(entry, Action::Redo);
+    }
+
+    fn apply(&mut self, entry: HistoryEntry, action: Action) {
+        let history = *self;
+        let mut notifications = self.notifications;
+        let mut update_signal = self.update_signal;
+        let (value, name) = match action {
+            Action::Undo => (entry.old.clone(), "undo"),
+            Action::Redo => (entry.new.clone(), "redo"),
+        };
+        let title = format!("Failed to {name} `{}`", entry.describe());
+        let res = self
+            .mutation_queue
+            .mutate(entry.target.clone(), entry.path.clone(), value);
+
+        spawn(async move {
+            let (mut done, mut pending) = match action {
+                Action::Undo => (history.redo, history.undo),
+                Action::Redo => (history.undo, history.redo),
+            };
+
+            match res.await {
+                Some(Ok(_)) => {
+                    done.push(entry);
+                    update_signal.set(());
+                }
+                Some(Err(e)) => {
+                    pending.push(entry);
+                    notifications.push(title, e);
+                }
+                // Replaced by a newer undo/redo of the same field, which
+                // carries on from this one
+                None => done.push(entry),
+            }
+        });
+    }
+
+    /// Lets the next edit of the last entry's field merge into it, until
+    /// `MERGE_WINDOW` passes without edits.
+    fn open_merge_window(&mut self) {
+        let mut history = *self;
+        let generation = self.close_merge_window();
+
+        self.is_mergeable.set(true);
+
+        // Not tied to the caller scope, the inspector may be closed meanwhile
+        spawn_forever(async move {
+            sleep(MERGE_WINDOW).await;
+
+            if *history.generation.peek() == generation {
+                history.is_mergeable.set(false);
+            }
+        });
+    }
+
+    fn close_merge_window(&mut self) -> u32 {
+        self.generation += 1;
+        self.is_mergeable.set(false);
+
+        *self.generation.peek()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Action {
+    Undo,
+    Redo,
+}
@@ -1,9 +1,19 @@
+mod audit_log_state;
 mod connection_state;
 mod entities_tool_state;
+mod history_state;
+mod mutation_queue_state;
+mod notifications_state;
+mod query_tool_state;
 mod resources_tool_state;
 mod types_tool_state;
 
+pub use audit_log_state::*;
 pub use connection_state::*;
 pub use entities_tool_state::*;
+pub use history_state::*;
+pub use mutation_queue_state::*;
+pub use notifications_state::*;
+pub use query_tool_state::*;
 pub use resources_tool_state::*;
 pub use types_tool_state::*;
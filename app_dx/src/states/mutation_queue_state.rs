@@ -0,0 +1,151 @@
+use client::{BrpClient, ClientError, Entity, ScriptStep, Value};
+use dioxus::prelude::*;
+use futures::channel::oneshot;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    future::Future,
+    time::Duration,
+};
+
+use crate::{states::AuditLogState, utils::sleep};
+
+/// Time to wait for more edits of the same field before sending them.
+const DEBOUNCE: Duration = Duration::from_millis(50);
+
+/// What a mutation targets.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MutationTarget {
+    /// Entity and component type path
+    Component(Entity, String),
+    /// Resource type path
+    Resource(String),
+}
+
+struct QueuedMutation {
+    path: String,
+    value: Value,
+    sender: oneshot::Sender<Result<(), ClientError>>,
+}
+
+/// Sends mutations one at a time per component (or resource), coalescing the
+/// edits of a field made while waiting, so rapid edits (e.g. typing a number)
+/// don't flood the server and the last edit always wins, also across nested
+/// paths (e.g. `.scale` and `.scale.x`).
+#[derive(Clone, Copy)]
+pub struct MutationQueueState {
+    audit_log: AuditLogState,
+    client: Memo<BrpClient>,
+    in_flight: Signal<BTreeSet<MutationTarget>>,
+    /// In the order they'll be sent, at most one per path
+    queued: Signal<BTreeMap<MutationTarget, Vec<QueuedMutation>>>,
+}
+
+impl MutationQueueState {
+    pub fn new(client: Memo<BrpClient>, audit_log: AuditLogState) -> Self {
+        Self {
+            audit_log,
+            client,
+            in_flight: Signal::new(BTreeSet::new()),
+            queued: Signal::new(BTreeMap::new()),
+        }
+    }
+
+    /// Queues a mutation. Resolves to `None` when a newer edit of the same
+    /// field replaced it before it was sent.
+    pub fn mutate(
+        &mut self,
+        target: MutationTarget,
+        path: impl Into<String>,
+        value: Value,
+    ) -> impl Future<Output = Option<Result<(), ClientError>>> + 'static {
+        let path = path.into();
+        let (sender, receiver) = oneshot::channel();
+
+        {
+            let mut queued = self.queued.write();
+            let mutations = queued.entry(target.clone()).or_default();
+
+            // Replacing a queued mutation drops its sender, resolving it as
+            // `None`. The new one goes last, after the edits it came after.
+            mutations.retain(|mutation| mutation.path != path);
+            mutations.push(QueuedMutation {
+                path,
+                value,
+                sender,
+            });
+        }
+
+        if !self.in_flight.peek().contains(&target) {
+            let mut queue = *self;
+
+            self.in_flight.write().insert(target.clone());
+
+            // Not tied to the caller scope, so closing an inspector doesn't
+            // cancel its edits
+            spawn_forever(async move {
+                queue.flush(target).await;
+            });
+        }
+
+        async move { receiver.await.ok() }
+    }
+
+    async fn flush(&mut self, target: MutationTarget) {
+        sleep(DEBOUNCE).await;
+
+        loop {
+            let next = {
+                let mut queued = self.queued.write();
+                let mutations = queued.entry(target.clone()).or_default();
+                let next = (!mutations.is_empty()).then(|| mutations.remove(0));
+
+                if mutations.is_empty() {
+                    queued.remove(&target);
+                }
+
+                next
+            };
+            let Some(QueuedMutation {
+                path,
+                value,
+                sender,
+            }) = next
+            else {
+                break;
+            };
+            let client = self.client.peek().clone();
+            let (res, step) = match target.clone() {
+                MutationTarget::Component(id, component) => {
+                    let step = ScriptStep::MutateComponent {
+                        entity: self.audit_log.entity_ref(id),
+                        component: component.clone(),
+                        path: path.clone(),
+                        value: value.clone(),
+                    };
+
+                    (
+                        client.mutate_component(id, component, path, value).await,
+                        step,
+                    )
+                }
+                MutationTarget::Resource(resource) => {
+                    let step = ScriptStep::MutateResource {
+                        resource: resource.clone(),
+                        path: path.clone(),
+                        value: value.clone(),
+                    };
+
+                    (client.mutate_resource(resource, path, value).await, step)
+                }
+            };
+
+            if res.is_ok() {
+                self.audit_log.record(step);
+            }
+
+            let _ = sender.send(res);
+        }
+
+        self.in_flight.write().remove(&target);
+    }
+}
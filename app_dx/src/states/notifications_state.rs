@@ -0,0 +1,82 @@
+use client::{ClientError, ErrorCode};
+use dioxus::prelude::*;
+use std::time::Duration;
+
+use crate::utils::sleep;
+
+const TOAST_DURATION: Duration = Duration::from_secs(6);
+
+/// A `ClientError` ready to be displayed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorMessage {
+    pub message: String,
+    /// BRP error code, when the server answered with an error
+    pub code: Option<ErrorCode>,
+}
+
+impl From<&ClientError> for ErrorMessage {
+    fn from(error: &ClientError) -> Self {
+        match error {
+            ClientError::ServerError(error) => Self {
+                message: error.message.clone(),
+                code: Some(error.code),
+            },
+            error => Self {
+                message: error.to_string(),
+                code: None,
+            },
+        }
+    }
+}
+
+impl From<ClientError> for ErrorMessage {
+    fn from(error: ClientError) -> Self {
+        Self::from(&error)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Toast {
+    pub id: u32,
+    /// What failed, e.g. "Failed to mutate `Transform.translation`"
+    pub title: String,
+    pub error: ErrorMessage,
+}
+
+#[derive(Clone, Copy)]
+pub struct NotificationsState {
+    next_id: Signal<u32>,
+    pub toasts: Signal<Vec<Toast>>,
+}
+
+impl NotificationsState {
+    pub fn new() -> Self {
+        Self {
+            next_id: Signal::new(0),
+            toasts: Signal::new(vec![]),
+        }
+    }
+
+    /// Shows a toast that is dismissed after a few seconds.
+    pub fn push(&mut self, title: impl Into<String>, error: impl Into<ErrorMessage>) {
+        let id = self.next_id.cloned();
+        let mut state = *self;
+
+        self.next_id += 1;
+        self.toasts.push(Toast {
+            id,
+            title: title.into(),
+            error: error.into(),
+        });
+
+        // Not tied to the caller scope, it might be gone by then
+        spawn_forever(async move {
+            sleep(TOAST_DURATION).await;
+            state.dismiss(id);
+        });
+    }
+
+    pub fn dismiss(&mut self, id: u32) {
+        self.toasts.retain(|toast| toast.id != id);
+    }
+}
@@ -0,0 +1,22 @@
+use client::EntityItem;
+use dioxus::prelude::*;
+
+#[derive(Clone, Copy)]
+pub struct QueryToolState {
+    /// Components fetched (if present) and shown as result columns
+    pub option: Signal<Vec<String>>,
+    pub with: Signal<Vec<String>>,
+    pub without: Signal<Vec<String>>,
+    pub results: Signal<Option<Result<Vec<EntityItem>, String>>>,
+}
+
+impl QueryToolState {
+    pub fn new() -> Self {
+        Self {
+            option: Signal::new(vec![]),
+            with: Signal::new(vec![]),
+            without: Signal::new(vec![]),
+            results: Signal::new(None),
+        }
+    }
+}
@@ -1,5 +1,7 @@
-use client::Value;
-use std::time::Duration;
+use client::{JsonSchemaBevyType, SchemaKind, Value};
+use std::{collections::BTreeMap, time::Duration};
+
+use crate::bevy_type;
 
 #[cfg(target_arch = "wasm32")]
 use gloo_timers::future::TimeoutFuture;
@@ -59,6 +61,19 @@ pub fn get_object_path(parent_path: &Option<String>, key: &str) -> String {
     format!("{}.{key}", parent_path.as_ref().unwrap_or(&String::new()))
 }
 
+/// Registered types that reflect `Component`, as `(type_path, short_path)`
+/// pairs sorted by `short_path`.
+pub fn get_component_types(schema: &BTreeMap<String, JsonSchemaBevyType>) -> Vec<(String, String)> {
+    let mut components = schema
+        .values()
+        .filter(|bevy_type| bevy_type.reflect_types.iter().any(|ty| ty == "Component"))
+        .map(|bevy_type| (bevy_type.type_path.clone(), bevy_type.short_path.clone()))
+        .collect::<Vec<_>>();
+
+    components.sort_by(|(_, a), (_, b)| a.cmp(b));
+    components
+}
+
 pub fn get_short_type_name(full_path: &str) -> String {
     // Extract the base type name (part before any generics)
     let (base_path, generics) = match full_path.find('<') {
@@ -93,6 +108,214 @@ pub fn get_type_path_from_ref_value(value: &Value) -> Option<String> {
         })
 }
 
+/// Resolves a reflect path (e.g. `.translation.x` or `.items[2]`) against a
+/// serialized value of `bevy_type`, which tells how each segment maps to the
+/// serialized form: glam vectors are arrays, so `.x` is `[0]`, newtypes and
+/// `Option` are their inner value, so `.0` is the value itself, and enum
+/// variants are `{ "Variant": payload }`, so fields are in the payload.
+///
+/// Below types that aren't in the schema the path is resolved by the shape
+/// of the value alone.
+pub fn get_value_at_path_mut<'a, 's>(
+    value: &'a mut Value,
+    path: &str,
+    bevy_type: Option<&'s JsonSchemaBevyType>,
+    schema: &'s BTreeMap<String, JsonSchemaBevyType>,
+) -> Option<&'a mut Value> {
+    let mut current = value;
+    let mut current_type = bevy_type;
+    let mut rest = path;
+
+    while !rest.is_empty() {
+        let (segment, is_index) = if let Some(field) = rest.strip_prefix('.') {
+            let end = field.find(['.', '[']).unwrap_or(field.len());
+            rest = &field[end..];
+            (&field[..end], false)
+        } else if let Some(index) = rest.strip_prefix('[') {
+            let end = index.find(']')?;
+            rest = &index[end + 1..];
+            (&index[..end], true)
+        } else {
+            return None;
+        };
+
+        (current, current_type) = match current_type {
+            Some(bevy_type) => typed_path_step(current, segment, is_index, bevy_type, schema)?,
+            None => (untyped_path_step(current, segment, is_index)?, None),
+        };
+    }
+
+    Some(current)
+}
+
+/// Resolves one path segment of a value of a known type, along with the type
+/// of the resolved value.
+fn typed_path_step<'a, 's>(
+    value: &'a mut Value,
+    segment: &str,
+    is_index: bool,
+    bevy_type: &'s JsonSchemaBevyType,
+    schema: &'s BTreeMap<String, JsonSchemaBevyType>,
+) -> Option<(&'a mut Value, Option<&'s JsonSchemaBevyType>)> {
+    let field = (!is_index).then_some(segment);
+
+    // `None` is `null`, `Some(value)` is just `value`
+    if bevy_type.type_path.starts_with(bevy_type::OPTION_PREFIX) {
+        let some_ref = bevy_type
+            .one_of
+            .iter()
+            .find(|variant| variant.get("shortPath").and_then(Value::as_str) == Some("Some"))
+            .and_then(|variant| variant.get("prefixItems")?.get(0));
+
+        return match field {
+            Some("0") if !value.is_null() => Some((value, ref_type(some_ref, schema))),
+            _ => None,
+        };
+    }
+
+    // Glam types are serialized as flat arrays
+    if bevy_type.type_path.starts_with("glam::") && bevy_type.kind == SchemaKind::Struct {
+        let field = field?;
+        let idx = ["x", "y", "z", "w"]
+            .iter()
+            .position(|axis| *axis == field)?;
+
+        return Some((
+            value.get_mut(idx)?,
+            ref_type(bevy_type.properties.get(field), schema),
+        ));
+    }
+
+    match bevy_type.kind {
+        SchemaKind::Struct => {
+            let field = field?;
+
+            Some((
+                value.get_mut(field)?,
+                ref_type(bevy_type.properties.get(field), schema),
+            ))
+        }
+        SchemaKind::TupleStruct => {
+            tuple_path_step(value, field?, &bevy_type.prefix_items, true, schema)
+        }
+        SchemaKind::Tuple => tuple_path_step(value, field?, &bevy_type.prefix_items, false, schema),
+        SchemaKind::List | SchemaKind::Array | SchemaKind::Set if is_index => Some((
+            value.get_mut(segment.parse::<usize>().ok()?)?,
+            ref_type(bevy_type.items.as_ref(), schema),
+        )),
+        // Unit variants (`"Variant"`) have no fields
+        SchemaKind::Enum => {
+            let Value::Object(object) = value else {
+                return None;
+            };
+
+            if object.len() != 1 {
+                return None;
+            }
+
+            let (name, payload) = object.iter_mut().next()?;
+            let variant = bevy_type
+                .one_of
+                .iter()
+                .find(|variant| variant.get("shortPath").and_then(Value::as_str) == Some(name))?;
+
+            match variant.get("kind").and_then(Value::as_str)? {
+                "Struct" => {
+                    let field = field?;
+                    let field_ref = variant
+                        .get("properties")
+                        .and_then(|properties| properties.get(field));
+
+                    Some((payload.get_mut(field)?, ref_type(field_ref, schema)))
+                }
+                "Tuple" => {
+                    let prefix_items = variant.get("prefixItems").and_then(Value::as_array)?;
+
+                    tuple_path_step(payload, field?, prefix_items, true, schema)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// `.0`, `.1`, ... of a tuple, newtypes (`is_flattened` with a single field)
+/// are serialized as their inner value.
+fn tuple_path_step<'a, 's>(
+    value: &'a mut Value,
+    field: &str,
+    prefix_items: &'s [Value],
+    is_flattened: bool,
+    schema: &'s BTreeMap<String, JsonSchemaBevyType>,
+) -> Option<(&'a mut Value, Option<&'s JsonSchemaBevyType>)> {
+    let idx = field.parse::<usize>().ok()?;
+    let item_type = ref_type(prefix_items.get(idx), schema);
+
+    if is_flattened && prefix_items.len() == 1 {
+        return (idx == 0).then_some((value, item_type));
+    }
+
+    Some((value.get_mut(idx)?, item_type))
+}
+
+/// Resolves one path segment by the shape of the value, for types that
+/// aren't in the schema.
+fn untyped_path_step<'a>(
+    value: &'a mut Value,
+    segment: &str,
+    is_index: bool,
+) -> Option<&'a mut Value> {
+    let mut current = value;
+    let is_variant = matches!(
+        &*current,
+        Value::Object(object) if !is_index
+            && object.len() == 1
+            && !object.contains_key(segment)
+            && object.keys().all(|key| key.starts_with(char::is_uppercase))
+    );
+
+    if is_variant {
+        current = current.as_object_mut()?.values_mut().next()?;
+    }
+
+    if segment == "0" && !is_index && !current.is_array() && current.get("0").is_none() {
+        return Some(current);
+    }
+
+    match current {
+        Value::Object(object) if !is_index => object.get_mut(segment),
+        Value::Array(array) => {
+            let index = match segment {
+                "x" if !is_index => 0,
+                "y" if !is_index => 1,
+                "z" if !is_index => 2,
+                "w" if !is_index => 3,
+                _ => segment.parse::<usize>().ok()?,
+            };
+
+            array.get_mut(index)
+        }
+        _ => None,
+    }
+}
+
+fn ref_type<'s>(
+    type_ref: Option<&Value>,
+    schema: &'s BTreeMap<String, JsonSchemaBevyType>,
+) -> Option<&'s JsonSchemaBevyType> {
+    schema.get(&get_type_path_from_ref_value(type_ref?)?)
+}
+
+/// Splits an entry path like `[2].field` into `(2, ".field")`. Used by the
+/// collection editors, which give each entry a path relative to the
+/// collection and submit the whole collection.
+pub fn split_index_path(path: &str) -> Option<(usize, &str)> {
+    let (index, rest) = path.strip_prefix('[')?.split_once(']')?;
+
+    Some((index.parse().ok()?, rest))
+}
+
 fn process_generic_part(type_name: &str, generic_part: &str) -> String {
     // Ensure the generics start with '<' and find matching '>'
     if !generic_part.starts_with('<') || generic_part.len() < 2 {
@@ -165,3 +388,191 @@ pub fn value_to_string(value: &Value) -> String {
         .map(|s| s.to_string())
         .unwrap_or_else(|| value.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use client::{from_value, json};
+
+    fn schema() -> BTreeMap<String, JsonSchemaBevyType> {
+        from_value(json!({
+            "f32": {
+                "shortPath": "f32",
+                "typePath": "f32",
+                "kind": "Value",
+                "type": "float"
+            },
+            "glam::Vec3": {
+                "shortPath": "Vec3",
+                "typePath": "glam::Vec3",
+                "kind": "Struct",
+                "type": "object",
+                "properties": {
+                    "x": { "type": { "$ref": "#/$defs/f32" } },
+                    "y": { "type": { "$ref": "#/$defs/f32" } },
+                    "z": { "type": { "$ref": "#/$defs/f32" } }
+                },
+                "required": ["x", "y", "z"]
+            },
+            "alloc::vec::Vec<f32>": {
+                "shortPath": "Vec<f32>",
+                "typePath": "alloc::vec::Vec<f32>",
+                "kind": "List",
+                "type": "array",
+                "items": { "type": { "$ref": "#/$defs/f32" } }
+            },
+            "mock::Speed": {
+                "shortPath": "Speed",
+                "typePath": "mock::Speed",
+                "kind": "TupleStruct",
+                "type": "array",
+                "prefixItems": [{ "type": { "$ref": "#/$defs/glam::Vec3" } }]
+            },
+            "mock::Samples": {
+                "shortPath": "Samples",
+                "typePath": "mock::Samples",
+                "kind": "TupleStruct",
+                "type": "array",
+                "prefixItems": [{ "type": { "$ref": "#/$defs/alloc::vec::Vec<f32>" } }]
+            },
+            "mock::Pair": {
+                "shortPath": "Pair",
+                "typePath": "mock::Pair",
+                "kind": "TupleStruct",
+                "type": "array",
+                "prefixItems": [
+                    { "type": { "$ref": "#/$defs/f32" } },
+                    { "type": { "$ref": "#/$defs/mock::Speed" } }
+                ]
+            },
+            "core::option::Option<mock::Speed>": {
+                "shortPath": "Option<Speed>",
+                "typePath": "core::option::Option<mock::Speed>",
+                "kind": "Enum",
+                "type": "object",
+                "oneOf": [
+                    { "typePath": "core::option::Option<mock::Speed>::None", "shortPath": "None" },
+                    {
+                        "type": "array",
+                        "kind": "Tuple",
+                        "typePath": "core::option::Option<mock::Speed>::Some",
+                        "shortPath": "Some",
+                        "prefixItems": [{ "type": { "$ref": "#/$defs/mock::Speed" } }]
+                    }
+                ]
+            },
+            "mock::Shape": {
+                "shortPath": "Shape",
+                "typePath": "mock::Shape",
+                "kind": "Enum",
+                "type": "object",
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "kind": "Struct",
+                        "typePath": "mock::Shape::Circle",
+                        "shortPath": "Circle",
+                        "properties": { "center": { "type": { "$ref": "#/$defs/glam::Vec3" } } },
+                        "required": ["center"]
+                    },
+                    {
+                        "type": "array",
+                        "kind": "Tuple",
+                        "typePath": "mock::Shape::Moving",
+                        "shortPath": "Moving",
+                        "prefixItems": [{ "type": { "$ref": "#/$defs/glam::Vec3" } }]
+                    },
+                    {
+                        "type": "array",
+                        "kind": "Tuple",
+                        "typePath": "mock::Shape::Rect",
+                        "shortPath": "Rect",
+                        "prefixItems": [
+                            { "type": { "$ref": "#/$defs/f32" } },
+                            { "type": { "$ref": "#/$defs/f32" } }
+                        ]
+                    },
+                    { "typePath": "mock::Shape::Empty", "shortPath": "Empty" }
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    fn get(value: &Value, path: &str, type_path: &str) -> Option<Value> {
+        let schema = schema();
+        let mut value = value.clone();
+
+        get_value_at_path_mut(&mut value, path, schema.get(type_path), &schema).cloned()
+    }
+
+    #[test]
+    fn newtype_of_array_is_its_inner_value() {
+        let speed = json!([1.0, 2.0, 3.0]);
+        let samples = json!([0.5, 0.25]);
+
+        assert_eq!(get(&speed, ".0", "mock::Speed"), Some(speed.clone()));
+        assert_eq!(get(&speed, ".0.y", "mock::Speed"), Some(json!(2.0)));
+        assert_eq!(get(&samples, ".0", "mock::Samples"), Some(samples.clone()));
+        assert_eq!(get(&samples, ".0[1]", "mock::Samples"), Some(json!(0.25)));
+        assert_eq!(get(&samples, ".1", "mock::Samples"), None);
+    }
+
+    #[test]
+    fn tuple_struct_fields() {
+        let pair = json!([1.0, [4.0, 5.0, 6.0]]);
+
+        assert_eq!(get(&pair, ".0", "mock::Pair"), Some(json!(1.0)));
+        assert_eq!(get(&pair, ".1", "mock::Pair"), Some(json!([4.0, 5.0, 6.0])));
+        assert_eq!(get(&pair, ".1.0.z", "mock::Pair"), Some(json!(6.0)));
+    }
+
+    #[test]
+    fn option_is_its_inner_value() {
+        let some = json!([1.0, 2.0, 3.0]);
+        let type_path = "core::option::Option<mock::Speed>";
+
+        assert_eq!(get(&some, ".0", type_path), Some(some.clone()));
+        assert_eq!(get(&some, ".0.0.x", type_path), Some(json!(1.0)));
+        assert_eq!(get(&json!(null), ".0", type_path), None);
+    }
+
+    #[test]
+    fn enum_variant_fields() {
+        let circle = json!({ "Circle": { "center": [1.0, 2.0, 3.0] } });
+        let moving = json!({ "Moving": [1.0, 2.0, 3.0] });
+        let rect = json!({ "Rect": [4.0, 5.0] });
+
+        assert_eq!(get(&circle, ".center.z", "mock::Shape"), Some(json!(3.0)));
+        assert_eq!(
+            get(&moving, ".0", "mock::Shape"),
+            Some(json!([1.0, 2.0, 3.0]))
+        );
+        assert_eq!(get(&moving, ".0.y", "mock::Shape"), Some(json!(2.0)));
+        assert_eq!(get(&rect, ".1", "mock::Shape"), Some(json!(5.0)));
+        assert_eq!(get(&json!("Empty"), ".0", "mock::Shape"), None);
+    }
+
+    #[test]
+    fn writes_through_the_resolved_path() {
+        let schema = schema();
+        let mut value = json!({ "Moving": [1.0, 2.0, 3.0] });
+
+        *get_value_at_path_mut(&mut value, ".0.x", schema.get("mock::Shape"), &schema).unwrap() =
+            json!(9.0);
+
+        assert_eq!(value, json!({ "Moving": [9.0, 2.0, 3.0] }));
+    }
+
+    #[test]
+    fn unknown_types_resolve_by_shape() {
+        let value = json!({ "translation": [1.0, 2.0, 3.0], "items": [{ "A": { "b": 1 } }] });
+
+        assert_eq!(
+            get(&value, ".translation.y", "mock::Unknown"),
+            Some(json!(2.0))
+        );
+        assert_eq!(get(&value, ".items[0].b", "mock::Unknown"), Some(json!(1)));
+        assert_eq!(get(&value, ".missing", "mock::Unknown"), None);
+    }
+}
@@ -0,0 +1,61 @@
+use serde_json::{Value, from_value};
+use std::collections::BTreeMap;
+
+use crate::{BrpClient, BrpResponse, ClientError};
+
+/// Collects several calls and sends them as a single JSON-RPC batch request.
+pub struct Batch<'a> {
+    client: &'a BrpClient,
+    requests: Vec<(u32, Value)>,
+}
+
+impl<'a> Batch<'a> {
+    pub(crate) fn new(client: &'a BrpClient) -> Self {
+        Self {
+            client,
+            requests: vec![],
+        }
+    }
+
+    pub fn add(&mut self, method: &str, params: Option<Value>) -> &mut Self {
+        self.requests.push(self.client.request(method, params));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Sends all the calls in one round-trip. Results are returned in the same
+    /// order the calls were added, regardless of the order of the responses.
+    pub async fn send(self) -> Result<Vec<Result<Value, ClientError>>, ClientError> {
+        // An empty batch is an invalid JSON-RPC request
+        if self.requests.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let (ids, requests): (Vec<u32>, Vec<Value>) = self.requests.into_iter().unzip();
+        let res = self.client.transport.send(Value::Array(requests)).await?;
+
+        let mut responses = from_value::<Vec<BrpResponse<Value>>>(res)?
+            .into_iter()
+            .map(|res| match res {
+                BrpResponse::Success { id, result } => (id, Ok(result)),
+                BrpResponse::Error { id, error } => (id, Err(ClientError::ServerError(error))),
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        Ok(ids
+            .into_iter()
+            .map(|id| {
+                responses
+                    .remove(&id)
+                    .unwrap_or(Err(ClientError::MissingResponse(id)))
+            })
+            .collect())
+    }
+}
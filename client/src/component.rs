@@ -20,4 +20,5 @@ pub const POINTER_PRESS: &str = "bevy_picking::pointer::PointerPress";
 pub const SYSTEM_ID_MARKER: &str = "bevy_ecs::system::system_registry::SystemIdMarker"; // TODO: Replace with 0.17 equivalent
 pub const TEXT: &str = "bevy_ui::widget::text::Text";
 pub const TEXT_LAYOUT_INFO: &str = "bevy_text::pipeline::TextLayoutInfo";
+pub const TRANSFORM: &str = "bevy_transform::components::transform::Transform";
 pub const WINDOW: &str = "bevy_window::window::Window";
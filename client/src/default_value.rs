@@ -0,0 +1,272 @@
+use bevy_ecs::entity::Entity;
+use bevy_remote::schemas::json_schema::{JsonSchemaBevyType, SchemaKind, SchemaType};
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+
+const CHAR: &str = "char";
+const DURATION: &str = "core::time::Duration";
+const ENTITY: &str = "bevy_ecs::entity::Entity";
+const GLAM_DQUAT: &str = "glam::DQuat";
+const GLAM_QUAT: &str = "glam::Quat";
+pub(crate) const OPTION_PREFIX: &str = "core::option::Option<";
+const TRANSFORM: &str = "bevy_transform::components::transform::Transform";
+
+/// Field order of glam types, which are serialized as flat arrays.
+const GLAM_FIELDS: [&str; 11] = [
+    "x",
+    "y",
+    "z",
+    "w",
+    "x_axis",
+    "y_axis",
+    "z_axis",
+    "w_axis",
+    "matrix2",
+    "matrix3",
+    "translation",
+];
+
+/// Builds a starting value for a registered type from its schema (as
+/// returned by `BrpClient::get_schema`), in the serialized form
+/// `bevy_reflect` expects: zeros, empty strings, `false`, `None`, empty
+/// collections, the first variant of enums and structs built field by field.
+/// Glam vectors are zero, quaternions and matrices identity, `Transform` is
+/// `Transform::IDENTITY` (a zero scale would hide the entity).
+///
+/// Returns `None` if the type isn't in the schema, types referenced by it
+/// that aren't are `null`.
+pub fn default_value(
+    type_path: &str,
+    schema: &BTreeMap<String, JsonSchemaBevyType>,
+) -> Option<Value> {
+    schema
+        .get(type_path)
+        .map(|bevy_type| DefaultValue::new(schema).build(bevy_type))
+}
+
+/// Like [`default_value`], for a schema reference, e.g. the `items` of a
+/// list: `{ "type": { "$ref": "#/$defs/f32" } }`.
+pub fn default_value_for_ref(
+    type_ref: &Value,
+    schema: &BTreeMap<String, JsonSchemaBevyType>,
+) -> Option<Value> {
+    ref_type_path(type_ref).and_then(|type_path| default_value(type_path, schema))
+}
+
+/// Like [`default_value`], but with the given `variant` of the `type_path`
+/// enum selected, e.g. when switching variants in an editor.
+pub fn default_variant_value(
+    type_path: &str,
+    variant: &str,
+    schema: &BTreeMap<String, JsonSchemaBevyType>,
+) -> Option<Value> {
+    let bevy_type = schema.get(type_path)?;
+
+    match bevy_type.schema_type {
+        SchemaType::String => bevy_type
+            .one_of
+            .iter()
+            .find(|name| name.as_str() == Some(variant))
+            .cloned(),
+        _ => {
+            let entry = bevy_type
+                .one_of
+                .iter()
+                .find(|entry| entry.get("shortPath").and_then(Value::as_str) == Some(variant))?;
+
+            Some(DefaultValue::new(schema).variant(bevy_type, entry))
+        }
+    }
+}
+
+/// Schema walker, keeps track of the types being built to stop at recursive
+/// types (e.g. through `Vec<Self>` items, which are left empty anyway, or
+/// `Option<Box<Self>>`).
+struct DefaultValue<'a> {
+    schema: &'a BTreeMap<String, JsonSchemaBevyType>,
+    stack: Vec<&'a str>,
+}
+
+impl<'a> DefaultValue<'a> {
+    fn new(schema: &'a BTreeMap<String, JsonSchemaBevyType>) -> Self {
+        Self {
+            schema,
+            stack: vec![],
+        }
+    }
+
+    fn build_ref(&mut self, type_ref: &Value) -> Value {
+        let schema = self.schema;
+
+        match ref_type_path(type_ref).and_then(|type_path| schema.get(type_path)) {
+            Some(bevy_type) => self.build(bevy_type),
+            None => Value::Null,
+        }
+    }
+
+    fn build(&mut self, bevy_type: &'a JsonSchemaBevyType) -> Value {
+        let type_path = bevy_type.type_path.as_str();
+
+        if self.stack.contains(&type_path) {
+            return Value::Null;
+        }
+
+        self.stack.push(type_path);
+        let value = self.build_type(bevy_type);
+        self.stack.pop();
+
+        value
+    }
+
+    fn build_type(&mut self, bevy_type: &'a JsonSchemaBevyType) -> Value {
+        let type_path = bevy_type.type_path.as_str();
+
+        match type_path {
+            CHAR => return json!("\0"),
+            DURATION => return json!({ "secs": 0, "nanos": 0 }),
+            ENTITY => return json!(Entity::PLACEHOLDER),
+            TRANSFORM => {
+                return json!({
+                    "translation": [0.0, 0.0, 0.0],
+                    "rotation": [0.0, 0.0, 0.0, 1.0],
+                    "scale": [1.0, 1.0, 1.0],
+                });
+            }
+            _ if type_path.starts_with(OPTION_PREFIX) => return Value::Null,
+            _ if type_path.starts_with("glam::") && bevy_type.kind == SchemaKind::Struct => {
+                return Value::Array(self.glam(bevy_type));
+            }
+            _ => {}
+        }
+
+        match (&bevy_type.schema_type, &bevy_type.kind) {
+            (SchemaType::Boolean, _) => json!(false),
+            (SchemaType::Float, _) => json!(0.0),
+            (SchemaType::Int, _) | (SchemaType::Uint, _) => json!(0),
+            (SchemaType::String, SchemaKind::Enum) => {
+                bevy_type.one_of.first().cloned().unwrap_or_default()
+            }
+            (SchemaType::String, _) => json!(""),
+            (_, SchemaKind::Enum) => match bevy_type.one_of.first() {
+                Some(entry) => self.variant(bevy_type, entry),
+                None => Value::Null,
+            },
+            (_, SchemaKind::Struct) => Value::Object(self.properties(&bevy_type.properties)),
+            // Newtypes are serialized as their inner value
+            (_, SchemaKind::TupleStruct) if bevy_type.prefix_items.len() == 1 => {
+                self.build_ref(&bevy_type.prefix_items[0])
+            }
+            (_, SchemaKind::TupleStruct) | (_, SchemaKind::Tuple) => bevy_type
+                .prefix_items
+                .iter()
+                .map(|type_ref| self.build_ref(type_ref))
+                .collect(),
+            // Fixed size arrays need all their items
+            (_, SchemaKind::Array) => match (&bevy_type.items, array_len(type_path)) {
+                (Some(item_ref), Some(len)) => Value::Array(vec![self.build_ref(item_ref); len]),
+                _ => json!([]),
+            },
+            (_, SchemaKind::List) | (_, SchemaKind::Set) => json!([]),
+            (_, SchemaKind::Map) => json!({}),
+            _ => Value::Null,
+        }
+    }
+
+    /// Builds an enum value with the given `oneOf` entry as the variant,
+    /// serialized as `"Variant"` or `{ "Variant": payload }`. `Option` is
+    /// serialized as `null` or its inner value.
+    fn variant(&mut self, bevy_type: &JsonSchemaBevyType, entry: &Value) -> Value {
+        let name = entry
+            .get("shortPath")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let is_option = bevy_type.type_path.starts_with(OPTION_PREFIX);
+        let payload = match entry.get("kind").and_then(Value::as_str) {
+            Some("Struct") => entry
+                .get("properties")
+                .and_then(Value::as_object)
+                .map(|properties| Value::Object(self.properties(properties))),
+            Some("Tuple") => {
+                let prefix_items = entry
+                    .get("prefixItems")
+                    .and_then(Value::as_array)
+                    .cloned()
+                    .unwrap_or_default();
+
+                match prefix_items.as_slice() {
+                    [type_ref] => Some(self.build_ref(type_ref)),
+                    prefix_items => Some(
+                        prefix_items
+                            .iter()
+                            .map(|type_ref| self.build_ref(type_ref))
+                            .collect(),
+                    ),
+                }
+            }
+            _ => None,
+        };
+
+        match (payload, is_option) {
+            (None, true) => Value::Null,
+            (Some(payload), true) => payload,
+            (None, false) => json!(name),
+            (Some(payload), false) => json!({ name: payload }),
+        }
+    }
+
+    fn properties<'b>(
+        &mut self,
+        properties: impl IntoIterator<Item = (&'b String, &'b Value)>,
+    ) -> Map<String, Value> {
+        properties
+            .into_iter()
+            .map(|(key, type_ref)| (key.clone(), self.build_ref(type_ref)))
+            .collect()
+    }
+
+    /// Glam types are serialized as flat arrays: vectors are zero, quaternions
+    /// and matrices identity.
+    fn glam(&mut self, bevy_type: &JsonSchemaBevyType) -> Vec<Value> {
+        if [GLAM_QUAT, GLAM_DQUAT].contains(&bevy_type.type_path.as_str()) {
+            return vec![json!(0.0), json!(0.0), json!(0.0), json!(1.0)];
+        }
+
+        let mut fields = bevy_type.properties.iter().collect::<Vec<_>>();
+
+        fields.sort_by_key(|(key, _)| GLAM_FIELDS.iter().position(|field| field == key));
+        fields
+            .into_iter()
+            .enumerate()
+            .flat_map(|(idx, (key, type_ref))| {
+                let mut value = match self.build_ref(type_ref) {
+                    Value::Array(items) => items,
+                    value => vec![value],
+                };
+
+                // Matrix column vectors, `x_axis` is `[1, 0, 0]`, ...
+                if key.ends_with("_axis")
+                    && let Some(item) = value.get_mut(idx)
+                {
+                    *item = json!(1.0);
+                }
+
+                value
+            })
+            .collect()
+    }
+}
+
+pub(crate) fn ref_type_path(type_ref: &Value) -> Option<&str> {
+    type_ref
+        .get("type")?
+        .get("$ref")?
+        .as_str()?
+        .strip_prefix("#/$defs/")
+}
+
+/// Length of a fixed size array type, e.g. `[f32; 4]`.
+pub(crate) fn array_len(type_path: &str) -> Option<usize> {
+    let (_, len) = type_path.strip_suffix(']')?.rsplit_once(';')?;
+
+    len.trim().parse().ok()
+}
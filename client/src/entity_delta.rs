@@ -0,0 +1,54 @@
+use bevy_ecs::entity::Entity;
+use bevy_remote::builtin_methods::{BrpGetComponentsResponse, BrpGetComponentsWatchingResponse};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::EntityItem;
+
+/// Changes to an entity reported by a `+watch` request.
+#[derive(Debug)]
+pub struct EntityDelta {
+    pub id: Entity,
+    /// Components that were added or changed since the last delta
+    pub components: BTreeMap<String, Option<Value>>,
+    /// Components that were removed since the last delta
+    pub removed: Vec<String>,
+}
+
+impl From<EntityItem> for EntityDelta {
+    fn from(from: EntityItem) -> Self {
+        Self {
+            id: from.id,
+            components: from.components,
+            removed: vec![],
+        }
+    }
+}
+
+impl From<(Entity, BrpGetComponentsWatchingResponse)> for EntityDelta {
+    fn from(from: (Entity, BrpGetComponentsWatchingResponse)) -> Self {
+        // Reuse `EntityItem` conversion so non-reflectable components are
+        // handled the same way as in `bevy/get`
+        let (response, removed) = match from.1 {
+            BrpGetComponentsWatchingResponse::Lenient {
+                components,
+                removed,
+                errors,
+            } => (
+                BrpGetComponentsResponse::Lenient { components, errors },
+                removed,
+            ),
+            BrpGetComponentsWatchingResponse::Strict {
+                components,
+                removed,
+            } => (BrpGetComponentsResponse::Strict(components), removed),
+        };
+
+        let item: EntityItem = (from.0, response).into();
+
+        Self {
+            removed,
+            ..item.into()
+        }
+    }
+}
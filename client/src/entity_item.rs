@@ -1,12 +1,9 @@
 use bevy_ecs::entity::Entity;
-use bevy_remote::{
-    builtin_methods::{BrpGetComponentsResponse, BrpQueryRow},
-    error_codes::COMPONENT_ERROR,
-};
+use bevy_remote::builtin_methods::{BrpGetComponentsResponse, BrpQueryRow};
 use serde_json::Value;
 use std::collections::BTreeMap;
 
-use crate::{BrpError, component};
+use crate::{BrpError, EntityDelta, ErrorCode, component};
 
 #[derive(Debug)]
 pub struct EntityItem {
@@ -15,6 +12,24 @@ pub struct EntityItem {
 }
 
 impl EntityItem {
+    pub fn apply_delta(&mut self, delta: EntityDelta) {
+        for component in delta.removed {
+            self.components.remove(&component);
+        }
+
+        for (component, value) in delta.components {
+            match value {
+                Some(value) => {
+                    self.components.insert(component, Some(value));
+                }
+                // Don't override a known value with a failed serialization
+                None => {
+                    self.components.entry(component).or_insert(None);
+                }
+            }
+        }
+    }
+
     pub fn children(&self) -> Vec<Entity> {
         self.get_component_as::<Vec<Entity>>(component::CHILDREN)
             .unwrap_or_default()
@@ -51,7 +66,7 @@ impl From<(Entity, BrpGetComponentsResponse)> for EntityItem {
                         // This is OK as long as in `bevy/get` we don't query
                         // for NON-REFLECTABLE components that are not part of
                         // the entity
-                        if error.code == COMPONENT_ERROR {
+                        if error.code == ErrorCode::ComponentError {
                             empty_components.push(component.clone());
                         }
                     }
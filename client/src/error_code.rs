@@ -0,0 +1,81 @@
+use bevy_remote::error_codes;
+use serde::Deserialize;
+use std::fmt;
+
+/// Typed `bevy_remote::error_codes`, so callers can match on the reason a
+/// request failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(from = "i16")]
+pub enum ErrorCode {
+    /// Invalid JSON.
+    ParseError,
+    /// JSON sent is not a valid request object.
+    InvalidRequest,
+    /// The method does not exist / is not available, e.g. `+watch` methods
+    /// when the server doesn't support streaming.
+    MethodNotFound,
+    /// Invalid method parameter(s).
+    InvalidParams,
+    /// Internal error.
+    InternalError,
+    /// The entity doesn't exist, e.g. it was despawned.
+    EntityNotFound,
+    /// Could not reflect or find the component, or the mutation path is
+    /// invalid.
+    ComponentError,
+    /// The component isn't on the entity.
+    ComponentNotPresent,
+    /// An entity can't be its own parent.
+    SelfReparent,
+    /// Could not reflect or find the resource, or the mutation path is
+    /// invalid.
+    ResourceError,
+    /// The resource isn't in the world.
+    ResourceNotPresent,
+    /// A code this client doesn't know about.
+    Other(i16),
+}
+
+impl ErrorCode {
+    pub fn code(&self) -> i16 {
+        match self {
+            Self::ParseError => error_codes::PARSE_ERROR,
+            Self::InvalidRequest => error_codes::INVALID_REQUEST,
+            Self::MethodNotFound => error_codes::METHOD_NOT_FOUND,
+            Self::InvalidParams => error_codes::INVALID_PARAMS,
+            Self::InternalError => error_codes::INTERNAL_ERROR,
+            Self::EntityNotFound => error_codes::ENTITY_NOT_FOUND,
+            Self::ComponentError => error_codes::COMPONENT_ERROR,
+            Self::ComponentNotPresent => error_codes::COMPONENT_NOT_PRESENT,
+            Self::SelfReparent => error_codes::SELF_REPARENT,
+            Self::ResourceError => error_codes::RESOURCE_ERROR,
+            Self::ResourceNotPresent => error_codes::RESOURCE_NOT_PRESENT,
+            Self::Other(code) => *code,
+        }
+    }
+}
+
+impl From<i16> for ErrorCode {
+    fn from(code: i16) -> Self {
+        match code {
+            error_codes::PARSE_ERROR => Self::ParseError,
+            error_codes::INVALID_REQUEST => Self::InvalidRequest,
+            error_codes::METHOD_NOT_FOUND => Self::MethodNotFound,
+            error_codes::INVALID_PARAMS => Self::InvalidParams,
+            error_codes::INTERNAL_ERROR => Self::InternalError,
+            error_codes::ENTITY_NOT_FOUND => Self::EntityNotFound,
+            error_codes::COMPONENT_ERROR => Self::ComponentError,
+            error_codes::COMPONENT_NOT_PRESENT => Self::ComponentNotPresent,
+            error_codes::SELF_REPARENT => Self::SelfReparent,
+            error_codes::RESOURCE_ERROR => Self::ResourceError,
+            error_codes::RESOURCE_NOT_PRESENT => Self::ResourceNotPresent,
+            code => Self::Other(code),
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
@@ -1,27 +1,53 @@
-use bevy_remote::builtin_methods::{BrpGetComponentsResponse, BrpQueryRow};
+use bevy_remote::builtin_methods::{
+    BrpGetComponentsResponse, BrpGetComponentsWatchingResponse, BrpGetResourcesResponse,
+    BrpQueryRow, BrpSpawnEntityResponse,
+};
 use entity_kind::KIND_COMPONENTS;
-use futures::future::join_all;
+use futures::{
+    Stream, StreamExt,
+    future::{Either, select},
+    stream,
+};
 use serde::Deserialize;
 use std::{
     collections::BTreeMap,
     fmt,
+    pin::Pin,
     sync::{
         Arc,
         atomic::{AtomicU32, Ordering},
     },
+    task::{Context, Poll},
 };
 use thiserror::Error;
+use transport::{BoxStream, HttpTransport};
 
+mod batch;
 pub mod component;
+mod default_value;
+mod entity_delta;
 mod entity_item;
 mod entity_kind;
+mod error_code;
+mod query;
+mod script;
+pub mod transport;
+mod validate;
 
 // (Re)Exports
+pub use batch::Batch;
 pub use bevy_ecs::entity::Entity;
 pub use bevy_remote::schemas::json_schema::{JsonSchemaBevyType, SchemaKind, SchemaType};
+pub use default_value::{default_value, default_value_for_ref, default_variant_value};
+pub use entity_delta::EntityDelta;
 pub use entity_item::EntityItem;
 pub use entity_kind::EntityKind;
-pub use serde_json::{Value, from_value, json};
+pub use error_code::ErrorCode;
+pub use query::BrpQuery;
+pub use script::{EntityRef, Script, ScriptStep};
+pub use serde_json::{Map, Value, from_str, from_value, json, to_string_pretty};
+pub use transport::Transport;
+pub use validate::{ValidationError, validate};
 
 #[derive(Debug, Error)]
 pub enum ClientError {
@@ -31,6 +57,27 @@ pub enum ClientError {
     ParseError(#[from] serde_json::Error),
     #[error("server error: {0}")]
     ServerError(BrpError),
+    #[error("missing response for call {0}")]
+    MissingResponse(u32),
+    #[error("transport error: {0}")]
+    TransportError(String),
+    #[error("no entity named `{0}`")]
+    UnknownEntityName(String),
+    #[error("replay step {step} failed: {error}")]
+    ReplayError {
+        step: usize,
+        error: Box<ClientError>,
+    },
+}
+
+impl ClientError {
+    /// The BRP error code, if the server answered with an error.
+    pub fn code(&self) -> Option<ErrorCode> {
+        match self {
+            ClientError::ServerError(error) => Some(error.code),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -45,7 +92,7 @@ pub enum BrpResponse<T> {
 #[derive(Debug, Deserialize, Clone)]
 pub struct BrpError {
     /// Defines the general type of the error.
-    pub code: i16,
+    pub code: ErrorCode,
     /// Short, human-readable description of the error.
     pub message: String,
     /// Optional additional error data.
@@ -60,26 +107,61 @@ impl fmt::Display for BrpError {
     }
 }
 
+/// Added/removed components reported by `world.list_components+watch`.
+#[derive(Debug, Deserialize)]
+struct ComponentListDelta {
+    #[serde(default)]
+    added: Vec<String>,
+    #[serde(default)]
+    removed: Vec<String>,
+}
+
+/// Long-lived stream of results returned by `+watch` methods.
+pub struct WatchStream<T>(BoxStream<'static, Result<T, ClientError>>);
+
+impl<T> WatchStream<T> {
+    pub async fn next(&mut self) -> Option<Result<T, ClientError>> {
+        self.0.next().await
+    }
+}
+
+impl<T> fmt::Debug for WatchStream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WatchStream").finish_non_exhaustive()
+    }
+}
+
+impl<T> Stream for WatchStream<T> {
+    type Item = Result<T, ClientError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.0.poll_next_unpin(cx)
+    }
+}
+
 #[derive(Clone)]
 pub struct BrpClient {
     call_id: Arc<AtomicU32>,
-    client: reqwest::Client,
-    url: Arc<String>,
+    transport: Arc<dyn Transport>,
 }
 
 impl PartialEq for BrpClient {
     fn eq(&self, other: &Self) -> bool {
-        // Compare the `url` field (wrapped in `Arc`) for equality
-        Arc::ptr_eq(&self.url, &other.url) || *self.url == *other.url
+        // Compare the transport (wrapped in `Arc`) or its endpoint for equality
+        Arc::ptr_eq(&self.transport, &other.transport)
+            || self.transport.endpoint() == other.transport.endpoint()
     }
 }
 
 impl BrpClient {
     pub fn new(url: impl Into<String>) -> Self {
+        Self::with_transport(HttpTransport::new(url))
+    }
+
+    pub fn with_transport(transport: impl Transport + 'static) -> Self {
         Self {
             call_id: Arc::new(AtomicU32::new(0)),
-            client: reqwest::Client::new(),
-            url: Arc::new(url.into()),
+            transport: Arc::new(transport),
         }
     }
 
@@ -87,32 +169,71 @@ impl BrpClient {
         self.call_id.fetch_add(1, Ordering::SeqCst)
     }
 
-    async fn call(&self, method: &str, params: Option<Value>) -> Result<Value, ClientError> {
+    /// Builds a JSON-RPC request, returns it along with its call ID.
+    fn request(&self, method: &str, params: Option<Value>) -> (u32, Value) {
         let call_id = self.next_call_id();
-        let res = self
-            .client
-            .post(self.url.as_str())
-            .json(&json!({
-                "method": method,
-                "id": call_id,
-                "jsonrpc": "2.0",
-                "params": params
-            }))
-            .send()
+        let request = json!({
+            "method": method,
+            "id": call_id,
+            "jsonrpc": "2.0",
+            "params": params
+        });
+
+        (call_id, request)
+    }
+
+    pub fn batch(&self) -> Batch<'_> {
+        Batch::new(self)
+    }
+
+    async fn call(&self, method: &str, params: Option<Value>) -> Result<Value, ClientError> {
+        let (_, request) = self.request(method, params);
+        let res = self.transport.send(request).await?;
+
+        parse_response(res)
+    }
+
+    /// Same as `call` but for `+watch` methods, which keep sending responses
+    /// until the stream is dropped.
+    async fn call_stream(
+        &self,
+        method: &str,
+        params: Option<Value>,
+    ) -> Result<WatchStream<Value>, ClientError> {
+        let (_, request) = self.request(method, params);
+        let res = self.transport.send_stream(request).await?;
+
+        Ok(WatchStream(Box::pin(res.0.map(|res| parse_response(res?)))))
+    }
+
+    pub async fn despawn(&self, id: Entity) -> Result<(), ClientError> {
+        self.call("world.despawn_entity", Some(json!({ "entity": id })))
             .await?;
 
-        let res = res.json::<BrpResponse<Value>>().await?;
+        Ok(())
+    }
+
+    /// Despawns the entity and all its descendants, deepest ones first.
+    pub async fn despawn_recursive(&self, id: Entity) -> Result<(), ClientError> {
+        let mut entities = vec![id];
+        let mut idx = 0;
+
+        // Walk the hierarchy breadth-first, so reversing the list yields
+        // children before their parents
+        while let Some(&current) = entities.get(idx) {
+            entities.extend(self.get_children_ids(current).await?);
+            idx += 1;
+        }
 
-        match res {
-            BrpResponse::Success { result, .. } => Ok(result),
-            BrpResponse::Error { error, .. } => Err(ClientError::ServerError(error)),
+        for id in entities.into_iter().rev() {
+            self.despawn(id).await?;
         }
+
+        Ok(())
     }
 
     pub async fn get(&self, id: Entity) -> Result<EntityItem, ClientError> {
-        let components = self
-            .call("world.list_components", Some(json!({ "entity": id })))
-            .await?;
+        let components = self.list_components(id).await?;
 
         let entity = self
             .call(
@@ -134,26 +255,25 @@ impl BrpClient {
             components
         };
 
-        let futures = ids
-            .into_iter()
-            .map(async |id| -> Result<EntityItem, ClientError> {
-                let entity = self
-                    .call(
-                        "world.get_components",
-                        Some(json!({
-                            "entity": id,
-                            "components": components
-                        })),
-                    )
-                    .await?;
+        let mut batch = self.batch();
 
-                Ok((id, from_value::<BrpGetComponentsResponse>(entity)?).into())
-            });
+        for id in ids.iter() {
+            batch.add(
+                "world.get_components",
+                Some(json!({
+                    "entity": id,
+                    "components": components
+                })),
+            );
+        }
 
-        // Wait for all futures to complete
-        let results = join_all(futures).await;
+        // Fetch all entities in a single round-trip
+        let results = batch.send().await?;
 
-        results.into_iter().collect()
+        ids.into_iter()
+            .zip(results)
+            .map(|(id, entity)| Ok((id, from_value::<BrpGetComponentsResponse>(entity?)?).into()))
+            .collect()
     }
 
     pub async fn get_children(
@@ -161,45 +281,35 @@ impl BrpClient {
         parent_id: Option<Entity>,
     ) -> Result<Vec<EntityItem>, ClientError> {
         let entities = match parent_id {
-            Some(id) => {
-                // Get the parent entity so we can get its children IDs
-                let parent = self
-                    .call(
-                        "world.get_components",
-                        Some(json!({
-                            "entity": id,
-                            "components": [component::CHILDREN]
-                        })),
-                    )
-                    .await?;
-
-                let parent: EntityItem =
-                    (id, from_value::<BrpGetComponentsResponse>(parent)?).into();
-
-                parent.children()
-            }
+            Some(id) => self.get_children_ids(id).await?,
             None => {
-                let res = self
-                    .call(
-                        "world.query",
-                        Some(json!({
-                            "data": {},
-                            "filter": {
-                                "without": [component::CHILD_OF]
-                            },
-                        })),
-                    )
-                    .await?;
-
-                let res = from_value::<Vec<BrpQueryRow>>(res)?;
-
-                res.into_iter().map(|row| row.entity).collect()
+                let query = BrpQuery::new().without([component::CHILD_OF]);
+                let res = self.query(&query).await?;
+
+                res.into_iter().map(|entity| entity.id).collect()
             }
         };
 
         self.get_many(entities).await
     }
 
+    async fn get_children_ids(&self, id: Entity) -> Result<Vec<Entity>, ClientError> {
+        // Get the parent entity so we can get its children IDs
+        let parent = self
+            .call(
+                "world.get_components",
+                Some(json!({
+                    "entity": id,
+                    "components": [component::CHILDREN]
+                })),
+            )
+            .await?;
+
+        let parent: EntityItem = (id, from_value::<BrpGetComponentsResponse>(parent)?).into();
+
+        Ok(parent.children())
+    }
+
     pub async fn get_resource(&self, resource: String) -> Result<Value, ClientError> {
         let res = self
             .call(
@@ -210,7 +320,7 @@ impl BrpClient {
             )
             .await?;
 
-        Ok(res)
+        Ok(from_value::<BrpGetResourcesResponse>(res)?.value)
     }
 
     pub async fn get_schema(&self) -> Result<BTreeMap<String, JsonSchemaBevyType>, ClientError> {
@@ -219,6 +329,44 @@ impl BrpClient {
         Ok(schema)
     }
 
+    pub async fn insert_components(
+        &self,
+        id: Entity,
+        components: BTreeMap<String, Value>,
+    ) -> Result<(), ClientError> {
+        self.call(
+            "world.insert_components",
+            Some(json!({
+                "entity": id,
+                "components": components,
+            })),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_resource(&self, resource: String, value: Value) -> Result<(), ClientError> {
+        self.call(
+            "world.insert_resources",
+            Some(json!({
+                "resource": resource,
+                "value": value,
+            })),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_components(&self, id: Entity) -> Result<Vec<String>, ClientError> {
+        let res = self
+            .call("world.list_components", Some(json!({ "entity": id })))
+            .await?;
+        let components = from_value::<Vec<String>>(res)?;
+        Ok(components)
+    }
+
     pub async fn list_resources(&self) -> Result<Vec<String>, ClientError> {
         let res = self.call("world.list_resources", None).await?;
         let resources = from_value::<Vec<String>>(res)?;
@@ -246,8 +394,312 @@ impl BrpClient {
         Ok(())
     }
 
+    pub async fn mutate_resource(
+        &self,
+        resource: String,
+        path: String,
+        value: Value,
+    ) -> Result<(), ClientError> {
+        self.call(
+            "world.mutate_resources",
+            Some(json!({
+                "resource": resource,
+                "path": path,
+                "value": value,
+            })),
+        )
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn ping(&self) -> Result<(), ClientError> {
         self.call("rpc.discover", None).await?;
         Ok(())
     }
+
+    pub async fn query(&self, query: &BrpQuery) -> Result<Vec<EntityItem>, ClientError> {
+        let res = self.call("world.query", Some(query.to_params())).await?;
+        let rows = from_value::<Vec<BrpQueryRow>>(res)?;
+        Ok(rows.into_iter().map(EntityItem::from).collect())
+    }
+
+    pub async fn remove_components(
+        &self,
+        id: Entity,
+        components: Vec<String>,
+    ) -> Result<(), ClientError> {
+        self.call(
+            "world.remove_components",
+            Some(json!({
+                "entity": id,
+                "components": components,
+            })),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_resource(&self, resource: String) -> Result<(), ClientError> {
+        self.call(
+            "world.remove_resources",
+            Some(json!({
+                "resource": resource,
+            })),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Applies the steps of a script in order, stopping at the first one
+    /// that fails. Entities referenced by name are resolved against the
+    /// current world, taking the first match if several share a name.
+    pub async fn replay(&self, script: &Script) -> Result<(), ClientError> {
+        let mut names = None;
+
+        for (step, script_step) in script.steps.iter().enumerate() {
+            self.replay_step(script_step, &mut names)
+                .await
+                .map_err(|error| ClientError::ReplayError {
+                    step,
+                    error: Box::new(error),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// `names` caches the named entities, it's cleared when entities are
+    /// spawned or despawned.
+    async fn replay_step(
+        &self,
+        step: &ScriptStep,
+        names: &mut Option<BTreeMap<String, Entity>>,
+    ) -> Result<(), ClientError> {
+        match step {
+            ScriptStep::Despawn { entity } => {
+                let id = self.resolve_entity(entity, names).await?;
+                *names = None;
+                self.despawn_recursive(id).await
+            }
+            ScriptStep::InsertComponents { entity, components } => {
+                let id = self.resolve_entity(entity, names).await?;
+
+                // Inserting a `Name` changes how entities are resolved
+                if components.contains_key(component::NAME) {
+                    *names = None;
+                }
+
+                self.insert_components(id, components.clone()).await
+            }
+            ScriptStep::MutateComponent {
+                entity,
+                component,
+                path,
+                value,
+            } => {
+                let id = self.resolve_entity(entity, names).await?;
+
+                if component == component::NAME {
+                    *names = None;
+                }
+
+                self.mutate_component(id, component.clone(), path.clone(), value.clone())
+                    .await
+            }
+            ScriptStep::MutateResource {
+                resource,
+                path,
+                value,
+            } => {
+                self.mutate_resource(resource.clone(), path.clone(), value.clone())
+                    .await
+            }
+            ScriptStep::RemoveComponents { entity, components } => {
+                let id = self.resolve_entity(entity, names).await?;
+
+                if components
+                    .iter()
+                    .any(|component| component == component::NAME)
+                {
+                    *names = None;
+                }
+
+                self.remove_components(id, components.clone()).await
+            }
+            ScriptStep::Spawn { components } => {
+                *names = None;
+                self.spawn(components.clone()).await.map(|_| ())
+            }
+        }
+    }
+
+    async fn resolve_entity(
+        &self,
+        entity: &EntityRef,
+        names: &mut Option<BTreeMap<String, Entity>>,
+    ) -> Result<Entity, ClientError> {
+        let name = match entity {
+            EntityRef::Id(id) => return Ok(*id),
+            EntityRef::Name(name) => name,
+        };
+
+        if names.is_none() {
+            let entities = self
+                .query(&BrpQuery::new().components([component::NAME]))
+                .await?;
+            let mut map = BTreeMap::new();
+
+            for entity in entities {
+                if let Some(name) = entity.name() {
+                    map.entry(name).or_insert(entity.id);
+                }
+            }
+
+            *names = Some(map);
+        }
+
+        names
+            .as_ref()
+            .and_then(|names| names.get(name).copied())
+            .ok_or_else(|| ClientError::UnknownEntityName(name.clone()))
+    }
+
+    pub async fn spawn(&self, components: BTreeMap<String, Value>) -> Result<Entity, ClientError> {
+        let res = self
+            .call(
+                "world.spawn_entity",
+                Some(json!({
+                    "components": components,
+                })),
+            )
+            .await?;
+
+        Ok(from_value::<BrpSpawnEntityResponse>(res)?.entity)
+    }
+
+    /// Streams changes of the given `components` using
+    /// `world.get_components+watch`.
+    pub async fn watch(
+        &self,
+        id: Entity,
+        components: Vec<String>,
+    ) -> Result<WatchStream<EntityDelta>, ClientError> {
+        let res = self
+            .call_stream(
+                "world.get_components+watch",
+                Some(json!({
+                    "entity": id,
+                    "components": components,
+                })),
+            )
+            .await?;
+
+        Ok(WatchStream(Box::pin(res.0.map(move |res| {
+            Ok((id, from_value::<BrpGetComponentsWatchingResponse>(res?)?).into())
+        }))))
+    }
+
+    async fn watch_component_list(
+        &self,
+        id: Entity,
+    ) -> Result<WatchStream<ComponentListDelta>, ClientError> {
+        let res = self
+            .call_stream("world.list_components+watch", Some(json!({ "entity": id })))
+            .await?;
+
+        Ok(WatchStream(Box::pin(
+            res.0.map(|res| Ok(from_value::<ComponentListDelta>(res?)?)),
+        )))
+    }
+
+    /// Streams all the changes of an entity, including components being
+    /// inserted or removed. Only changes are reported, use `get` to fetch the
+    /// initial state. Servers without `+watch` methods may reject them only
+    /// as the first item.
+    pub async fn watch_entity(&self, id: Entity) -> Result<WatchStream<EntityDelta>, ClientError> {
+        let components = self.list_components(id).await?;
+        let list = self.watch_component_list(id).await?;
+        let values = self.watch(id, components.clone()).await?;
+        let state = (self.clone(), components, values, list);
+
+        let deltas = stream::unfold(
+            state,
+            move |(client, mut components, mut values, mut list)| async move {
+                let next = match select(values.0.next(), list.0.next()).await {
+                    Either::Left((delta, _)) => Either::Left(delta),
+                    Either::Right((list_delta, _)) => Either::Right(list_delta),
+                };
+
+                let delta = match next {
+                    Either::Left(delta) => delta?,
+                    Either::Right(list_delta) => match list_delta? {
+                        Ok(list_delta) => {
+                            components.retain(|component| !list_delta.removed.contains(component));
+                            components.extend(list_delta.added.iter().cloned());
+
+                            // Restart the values watcher so it also covers
+                            // the inserted components
+                            match client.watch(id, components.clone()).await {
+                                Ok(new_values) => {
+                                    values = new_values;
+                                    client.get_added_components(id, list_delta).await
+                                }
+                                Err(e) => Err(e),
+                            }
+                        }
+                        Err(e) => Err(e),
+                    },
+                };
+
+                Some((delta, (client, components, values, list)))
+            },
+        );
+
+        Ok(WatchStream(Box::pin(deltas)))
+    }
+
+    /// Watchers only report changes, so inserted components need to be
+    /// fetched explicitly.
+    async fn get_added_components(
+        &self,
+        id: Entity,
+        list_delta: ComponentListDelta,
+    ) -> Result<EntityDelta, ClientError> {
+        let added = if list_delta.added.is_empty() {
+            EntityDelta {
+                id,
+                components: BTreeMap::new(),
+                removed: vec![],
+            }
+        } else {
+            let entity = self
+                .call(
+                    "world.get_components",
+                    Some(json!({
+                        "entity": id,
+                        "components": list_delta.added
+                    })),
+                )
+                .await?;
+
+            let entity: EntityItem = (id, from_value::<BrpGetComponentsResponse>(entity)?).into();
+
+            entity.into()
+        };
+
+        Ok(EntityDelta {
+            removed: list_delta.removed,
+            ..added
+        })
+    }
+}
+
+fn parse_response(res: Value) -> Result<Value, ClientError> {
+    match from_value::<BrpResponse<Value>>(res)? {
+        BrpResponse::Success { result, .. } => Ok(result),
+        BrpResponse::Error { error, .. } => Err(ClientError::ServerError(error)),
+    }
 }
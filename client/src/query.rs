@@ -0,0 +1,79 @@
+use serde_json::{Value, json};
+
+/// Builder for `world.query` requests.
+///
+/// ```ignore
+/// let query = BrpQuery::new()
+///     .components([component::NAME])
+///     .option([component::CHILDREN])
+///     .without([component::CHILD_OF]);
+///
+/// let entities = client.query(&query).await?;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BrpQuery {
+    components: Vec<String>,
+    option: Vec<String>,
+    has: Vec<String>,
+    with: Vec<String>,
+    without: Vec<String>,
+    strict: bool,
+}
+
+impl BrpQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Components to fetch, entities without them are skipped
+    pub fn components(mut self, components: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.components
+            .extend(components.into_iter().map(Into::into));
+        self
+    }
+
+    /// Components to fetch if the entity has them
+    pub fn option(mut self, components: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.option.extend(components.into_iter().map(Into::into));
+        self
+    }
+
+    /// Components to check for presence, without fetching their value
+    pub fn has(mut self, components: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.has.extend(components.into_iter().map(Into::into));
+        self
+    }
+
+    /// Only match entities with these components
+    pub fn with(mut self, components: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.with.extend(components.into_iter().map(Into::into));
+        self
+    }
+
+    /// Only match entities without these components
+    pub fn without(mut self, components: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.without.extend(components.into_iter().map(Into::into));
+        self
+    }
+
+    /// Fail on invalid components instead of skipping them
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub(crate) fn to_params(&self) -> Value {
+        json!({
+            "data": {
+                "components": self.components,
+                "option": self.option,
+                "has": self.has,
+            },
+            "filter": {
+                "with": self.with,
+                "without": self.without,
+            },
+            "strict": self.strict,
+        })
+    }
+}
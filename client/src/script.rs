@@ -0,0 +1,77 @@
+use bevy_ecs::entity::Entity;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// How a script step refers to an entity. IDs change between runs, so names
+/// are preferred; IDs are only used for unnamed entities.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityRef {
+    /// Entity with the given `Name` component
+    Name(String),
+    Id(Entity),
+}
+
+impl EntityRef {
+    pub fn new(id: Entity, name: Option<String>) -> Self {
+        match name {
+            Some(name) => Self::Name(name),
+            None => Self::Id(id),
+        }
+    }
+}
+
+/// A recorded BRP write, see [`Script`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum ScriptStep {
+    Despawn {
+        entity: EntityRef,
+    },
+    InsertComponents {
+        entity: EntityRef,
+        components: BTreeMap<String, Value>,
+    },
+    MutateComponent {
+        entity: EntityRef,
+        component: String,
+        path: String,
+        value: Value,
+    },
+    MutateResource {
+        resource: String,
+        path: String,
+        value: Value,
+    },
+    RemoveComponents {
+        entity: EntityRef,
+        components: Vec<String>,
+    },
+    /// Spawned entities can be referenced by later steps through their
+    /// `Name`, if they have one.
+    Spawn {
+        components: BTreeMap<String, Value>,
+    },
+}
+
+/// A list of changes that can be re-applied to another game instance with
+/// `BrpClient::replay`, e.g.:
+///
+/// ```json
+/// {
+///   "steps": [
+///     {
+///       "op": "mutate_component",
+///       "entity": { "name": "Player" },
+///       "component": "my_game::Speed",
+///       "path": ".0",
+///       "value": 4.5
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Script {
+    pub steps: Vec<ScriptStep>,
+}
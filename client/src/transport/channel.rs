@@ -0,0 +1,82 @@
+use futures::{
+    StreamExt,
+    channel::mpsc::{self, UnboundedReceiver, UnboundedSender},
+};
+use serde_json::Value;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::{
+    ClientError, WatchStream,
+    transport::{BoxFuture, Transport},
+};
+
+static NEXT_CHANNEL_ID: AtomicU32 = AtomicU32::new(0);
+
+/// A request received by the other end of a `ChannelTransport`.
+pub struct ChannelRequest {
+    /// JSON-RPC request, or an array of them for batch requests
+    pub request: Value,
+    /// Where to send the response(s). Regular requests expect a single
+    /// response, `+watch` requests keep receiving them until it's dropped.
+    pub responder: UnboundedSender<Value>,
+}
+
+/// In-memory transport, for tests or to run the client in the same process
+/// as the BRP server (e.g. forwarding requests to Bevy's `BrpSender`).
+pub struct ChannelTransport {
+    id: u32,
+    sender: UnboundedSender<ChannelRequest>,
+}
+
+impl ChannelTransport {
+    /// Creates the transport along with the receiver where the requests will
+    /// arrive.
+    pub fn new() -> (Self, UnboundedReceiver<ChannelRequest>) {
+        let (sender, receiver) = mpsc::unbounded();
+        let transport = Self {
+            id: NEXT_CHANNEL_ID.fetch_add(1, Ordering::SeqCst),
+            sender,
+        };
+
+        (transport, receiver)
+    }
+
+    fn open(&self, request: Value) -> Result<UnboundedReceiver<Value>, ClientError> {
+        let (responder, responses) = mpsc::unbounded();
+
+        self.sender
+            .unbounded_send(ChannelRequest { request, responder })
+            .map_err(|_| ClientError::TransportError("channel closed".to_string()))?;
+
+        Ok(responses)
+    }
+}
+
+impl Transport for ChannelTransport {
+    fn endpoint(&self) -> String {
+        format!("channel://{}", self.id)
+    }
+
+    fn send(&self, request: Value) -> BoxFuture<'_, Result<Value, ClientError>> {
+        Box::pin(async move {
+            self.open(request)?
+                .next()
+                .await
+                .ok_or_else(|| ClientError::TransportError("no response received".to_string()))
+        })
+    }
+
+    fn send_stream(
+        &self,
+        request: Value,
+    ) -> BoxFuture<'_, Result<WatchStream<Value>, ClientError>> {
+        Box::pin(async move {
+            // Bevy only responds to `+watch` requests when something changes,
+            // so don't wait for a response. A rejected request (e.g. method
+            // not found) is the first item, an error response.
+            let responses = self.open(request)?;
+
+            Ok(WatchStream(Box::pin(responses.map(Ok))))
+        })
+    }
+}
@@ -0,0 +1,105 @@
+use futures::{StreamExt, stream};
+use serde_json::Value;
+
+use crate::{
+    BrpResponse, ClientError, WatchStream,
+    transport::{BoxFuture, Transport},
+};
+
+/// Default transport, talks to `RemoteHttpPlugin`.
+pub struct HttpTransport {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl HttpTransport {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: url.into(),
+        }
+    }
+}
+
+impl Transport for HttpTransport {
+    fn endpoint(&self) -> String {
+        self.url.clone()
+    }
+
+    fn send(&self, request: Value) -> BoxFuture<'_, Result<Value, ClientError>> {
+        Box::pin(async move {
+            let res = self.client.post(&self.url).json(&request).send().await?;
+            Ok(res.json::<Value>().await?)
+        })
+    }
+
+    fn send_stream(
+        &self,
+        request: Value,
+    ) -> BoxFuture<'_, Result<WatchStream<Value>, ClientError>> {
+        Box::pin(async move {
+            let res = self.client.post(&self.url).json(&request).send().await?;
+
+            let is_event_stream = res
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.starts_with("text/event-stream"));
+
+            // Errors (e.g. method not found) are sent as a regular response
+            if !is_event_stream {
+                let res = res.json::<Value>().await?;
+
+                if let Ok(BrpResponse::Error { error, .. }) =
+                    serde_json::from_value::<BrpResponse<Value>>(res.clone())
+                {
+                    return Err(ClientError::ServerError(error));
+                }
+
+                return Ok(WatchStream(Box::pin(stream::once(async { Ok(res) }))));
+            }
+
+            let chunks = Box::pin(res.bytes_stream());
+            let events = stream::unfold(
+                (chunks, Vec::<u8>::new()),
+                |(mut chunks, mut buffer)| async move {
+                    loop {
+                        // Events are separated by a blank line
+                        if let Some(end) = buffer.windows(2).position(|w| w == b"\n\n") {
+                            let event = buffer.drain(..end + 2).collect::<Vec<_>>();
+
+                            match parse_event(&event) {
+                                Some(res) => return Some((res, (chunks, buffer))),
+                                None => continue,
+                            }
+                        }
+
+                        match chunks.next().await? {
+                            Ok(chunk) => buffer.extend_from_slice(&chunk),
+                            Err(e) => return Some((Err(e.into()), (chunks, buffer))),
+                        }
+                    }
+                },
+            );
+
+            Ok(WatchStream(Box::pin(events)))
+        })
+    }
+}
+
+/// Parses a server-sent event, ignoring events without `data`.
+fn parse_event(event: &[u8]) -> Option<Result<Value, ClientError>> {
+    let event = String::from_utf8_lossy(event);
+    let data = event
+        .lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .map(str::trim_start)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if data.is_empty() {
+        return None;
+    }
+
+    Some(serde_json::from_str::<Value>(&data).map_err(ClientError::from))
+}
@@ -0,0 +1,56 @@
+use serde_json::Value;
+
+use crate::{ClientError, WatchStream};
+
+mod channel;
+mod http;
+mod replay;
+
+pub use channel::{ChannelRequest, ChannelTransport};
+pub use http::HttpTransport;
+pub use replay::{Exchange, Recording, RecordingTransport, ReplayTransport};
+
+/// Future returned by transports. `Send`, so the client can run on
+/// multi-threaded executors (e.g. tokio or Bevy's task pools), except on wasm,
+/// where browser futures aren't.
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxFuture<'a, T> = futures::future::BoxFuture<'a, T>;
+#[cfg(target_arch = "wasm32")]
+pub type BoxFuture<'a, T> = futures::future::LocalBoxFuture<'a, T>;
+
+/// Stream counterpart of [`BoxFuture`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxStream<'a, T> = futures::stream::BoxStream<'a, T>;
+#[cfg(target_arch = "wasm32")]
+pub type BoxStream<'a, T> = futures::stream::LocalBoxStream<'a, T>;
+
+/// `Send + Sync`, except on wasm, see [`BoxFuture`].
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSendSync: Send + Sync {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send + Sync> MaybeSendSync for T {}
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSendSync {}
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSendSync for T {}
+
+/// Carries JSON-RPC messages between a `BrpClient` and a BRP server.
+///
+/// Requests and responses are raw JSON-RPC values, either a single object or
+/// an array for batch requests.
+pub trait Transport: MaybeSendSync {
+    /// Identifies the other end, two clients with the same endpoint are
+    /// considered equal.
+    fn endpoint(&self) -> String;
+
+    /// Sends a request and waits for its response.
+    fn send(&self, request: Value) -> BoxFuture<'_, Result<Value, ClientError>>;
+
+    /// Sends a request to a `+watch` method, yielding every response as it
+    /// arrives. `+watch` methods only respond when something changes, so
+    /// this must not wait for a response: requests rejected by the server
+    /// either fail here, if the transport can tell right away, or yield the
+    /// error response as the first item.
+    fn send_stream(&self, request: Value)
+    -> BoxFuture<'_, Result<WatchStream<Value>, ClientError>>;
+}
@@ -0,0 +1,203 @@
+use bevy_remote::error_codes::INTERNAL_ERROR;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    BrpError, ClientError, ErrorCode, WatchStream,
+    transport::{BoxFuture, Transport},
+};
+
+/// A request and the response it got.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Exchange {
+    pub method: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+    /// JSON-RPC response, its `id` is replaced when replayed
+    pub response: Value,
+}
+
+/// A recorded session, can be saved as JSON and replayed later.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Recording {
+    pub exchanges: Vec<Exchange>,
+}
+
+/// Wraps another transport and records every request/response pair.
+/// `+watch` streams are passed through without being recorded.
+pub struct RecordingTransport<T> {
+    inner: T,
+    recording: Arc<Mutex<Recording>>,
+}
+
+impl<T: Transport> RecordingTransport<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            recording: Arc::new(Mutex::new(Recording::default())),
+        }
+    }
+
+    pub fn recording(&self) -> Recording {
+        self.recording.lock().unwrap().clone()
+    }
+
+    fn record(&self, request: &Value, response: &Value) {
+        let responses = as_messages(response);
+        let mut recording = self.recording.lock().unwrap();
+
+        for request in as_messages(request) {
+            let (method, params) = get_method_and_params(request);
+            let response = responses
+                .iter()
+                .find(|response| response.get("id") == request.get("id"));
+
+            if let Some(response) = response {
+                recording.exchanges.push(Exchange {
+                    method: method.to_string(),
+                    params,
+                    response: (*response).clone(),
+                });
+            }
+        }
+    }
+}
+
+impl<T: Transport> Transport for RecordingTransport<T> {
+    fn endpoint(&self) -> String {
+        self.inner.endpoint()
+    }
+
+    fn send(&self, request: Value) -> BoxFuture<'_, Result<Value, ClientError>> {
+        Box::pin(async move {
+            let response = self.inner.send(request.clone()).await?;
+            self.record(&request, &response);
+            Ok(response)
+        })
+    }
+
+    fn send_stream(
+        &self,
+        request: Value,
+    ) -> BoxFuture<'_, Result<WatchStream<Value>, ClientError>> {
+        self.inner.send_stream(request)
+    }
+}
+
+/// Answers requests with the responses of a `Recording`.
+///
+/// Responses are replayed in the order they were recorded for each
+/// method/params pair, repeating the last one once exhausted, so polling
+/// keeps working.
+pub struct ReplayTransport {
+    responses: Mutex<HashMap<String, VecDeque<Value>>>,
+}
+
+impl ReplayTransport {
+    pub fn new(recording: Recording) -> Self {
+        let mut responses = HashMap::<String, VecDeque<Value>>::new();
+
+        for exchange in recording.exchanges {
+            responses
+                .entry(get_key(&exchange.method, &exchange.params))
+                .or_default()
+                .push_back(exchange.response);
+        }
+
+        Self {
+            responses: Mutex::new(responses),
+        }
+    }
+
+    fn respond(&self, request: &Value) -> Value {
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        let (method, params) = get_method_and_params(request);
+        let mut responses = self.responses.lock().unwrap();
+        let response = responses
+            .get_mut(&get_key(method, &params))
+            .and_then(|queue| {
+                if queue.len() > 1 {
+                    queue.pop_front()
+                } else {
+                    queue.front().cloned()
+                }
+            });
+
+        match response {
+            Some(mut response) => {
+                response["id"] = id;
+                response
+            }
+            None => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": {
+                    "code": INTERNAL_ERROR,
+                    "message": format!("No recorded response for `{method}`"),
+                },
+            }),
+        }
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn endpoint(&self) -> String {
+        "replay://".to_string()
+    }
+
+    fn send(&self, request: Value) -> BoxFuture<'_, Result<Value, ClientError>> {
+        let response = match &request {
+            Value::Array(requests) => Value::Array(
+                requests
+                    .iter()
+                    .map(|request| self.respond(request))
+                    .collect(),
+            ),
+            request => self.respond(request),
+        };
+
+        Box::pin(async move { Ok(response) })
+    }
+
+    fn send_stream(
+        &self,
+        _request: Value,
+    ) -> BoxFuture<'_, Result<WatchStream<Value>, ClientError>> {
+        Box::pin(async move {
+            Err(ClientError::ServerError(BrpError {
+                code: ErrorCode::MethodNotFound,
+                message: "Streams are not recorded".to_string(),
+                data: None,
+            }))
+        })
+    }
+}
+
+/// Splits a batch into its messages, single messages are returned as is.
+fn as_messages(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(values) => values.iter().collect(),
+        value => vec![value],
+    }
+}
+
+fn get_method_and_params(request: &Value) -> (&str, Option<Value>) {
+    let method = request
+        .get("method")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let params = request
+        .get("params")
+        .filter(|params| !params.is_null())
+        .cloned();
+
+    (method, params)
+}
+
+fn get_key(method: &str, params: &Option<Value>) -> String {
+    format!("{method} {}", params.clone().unwrap_or(Value::Null))
+}
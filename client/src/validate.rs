@@ -0,0 +1,384 @@
+use bevy_remote::schemas::json_schema::{JsonSchemaBevyType, SchemaKind, SchemaType};
+use serde_json::Value;
+use std::{collections::BTreeMap, fmt};
+
+use crate::default_value::{OPTION_PREFIX, array_len, ref_type_path};
+
+/// Why a value doesn't match its type, see [`validate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    /// Reflect path of the invalid field, relative to the validated value,
+    /// e.g. `.translation[1]`. Empty if it's the value itself.
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "`{}`: {}", self.path, self.message)
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Checks a serialized value against its type schema (as returned by
+/// `BrpClient::get_schema`) before sending it, so the user gets feedback
+/// without a server round trip: JSON types, integer ranges by type path
+/// (`u8`, `i16`, ...), required and unknown struct fields, enum variants and
+/// map keys.
+///
+/// Types that aren't in the schema and opaque types are not checked.
+pub fn validate(
+    value: &Value,
+    bevy_type: &JsonSchemaBevyType,
+    schema: &BTreeMap<String, JsonSchemaBevyType>,
+) -> Result<(), ValidationError> {
+    Validator { schema }.check(value, bevy_type, String::new())
+}
+
+struct Validator<'a> {
+    schema: &'a BTreeMap<String, JsonSchemaBevyType>,
+}
+
+impl Validator<'_> {
+    fn check_ref(
+        &self,
+        value: &Value,
+        type_ref: &Value,
+        path: String,
+    ) -> Result<(), ValidationError> {
+        match ref_type_path(type_ref).and_then(|type_path| self.schema.get(type_path)) {
+            Some(bevy_type) => self.check(value, bevy_type, path),
+            None => Ok(()),
+        }
+    }
+
+    fn check(
+        &self,
+        value: &Value,
+        bevy_type: &JsonSchemaBevyType,
+        path: String,
+    ) -> Result<(), ValidationError> {
+        let type_path = bevy_type.type_path.as_str();
+        let error = |message: String| {
+            Err(ValidationError {
+                path: path.clone(),
+                message,
+            })
+        };
+
+        if type_path.starts_with(OPTION_PREFIX) {
+            // `None` is `null`, `Some(value)` is just `value`
+            return match value {
+                Value::Null => Ok(()),
+                value => match some_type_ref(bevy_type) {
+                    Some(type_ref) => self.check_ref(value, type_ref, path),
+                    None => Ok(()),
+                },
+            };
+        }
+
+        // Glam types are serialized as flat arrays
+        if type_path.starts_with("glam::") && bevy_type.kind == SchemaKind::Struct {
+            let Some(items) = value.as_array().filter(|items| {
+                items
+                    .iter()
+                    .all(|item| item.is_number() || item.is_boolean())
+            }) else {
+                return error(format!(
+                    "expected an array of numbers for `{}`",
+                    bevy_type.short_path
+                ));
+            };
+
+            // Vectors and quaternions have an item per axis, matrices are
+            // flattened further and only checked for numbers
+            let axes = ["x", "y", "z", "w"]
+                .into_iter()
+                .map_while(|axis| Some((axis, bevy_type.properties.get(axis)?)))
+                .collect::<Vec<_>>();
+
+            if axes.is_empty() || axes.len() != bevy_type.properties.len() {
+                return Ok(());
+            }
+
+            if items.len() != axes.len() {
+                return error(format!(
+                    "expected {} items, got {}",
+                    axes.len(),
+                    items.len()
+                ));
+            }
+
+            return items
+                .iter()
+                .zip(axes)
+                .try_for_each(|(item, (axis, type_ref))| {
+                    self.check_ref(item, type_ref, format!("{path}.{axis}"))
+                });
+        }
+
+        match (&bevy_type.schema_type, &bevy_type.kind) {
+            (SchemaType::Boolean, _) if !value.is_boolean() => error("expected a boolean".into()),
+            (SchemaType::Float, _) => match value.as_f64() {
+                Some(number) if type_path == "f32" && number.abs() > f32::MAX as f64 => {
+                    error(format!("{number} is out of range for `f32`"))
+                }
+                Some(_) => Ok(()),
+                None => error("expected a number".into()),
+            },
+            (SchemaType::Int, _) | (SchemaType::Uint, _) => {
+                check_int(value, type_path).or_else(error)
+            }
+            (SchemaType::String, SchemaKind::Enum) => match value.as_str() {
+                Some(name) if bevy_type.one_of.iter().any(|variant| variant == name) => Ok(()),
+                _ => error(format!(
+                    "expected one of {}",
+                    bevy_type
+                        .one_of
+                        .iter()
+                        .map(Value::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            },
+            (SchemaType::String, _) => match value.as_str() {
+                Some(text) if type_path == "char" && text.chars().count() != 1 => {
+                    error("expected a single character".into())
+                }
+                Some(_) => Ok(()),
+                None => error("expected a string".into()),
+            },
+            (_, SchemaKind::Enum) => self.check_enum(value, bevy_type, path),
+            (_, SchemaKind::Struct) => match value.as_object() {
+                Some(_) => self.check_fields(value, bevy_type, path),
+                None => error(format!("expected an object for `{}`", bevy_type.short_path)),
+            },
+            // Newtypes are serialized as their inner value
+            (_, SchemaKind::TupleStruct) if bevy_type.prefix_items.len() == 1 => {
+                self.check_ref(value, &bevy_type.prefix_items[0], format!("{path}.0"))
+            }
+            (_, SchemaKind::TupleStruct) | (_, SchemaKind::Tuple) => {
+                self.check_prefix_items(value, &bevy_type.prefix_items, path)
+            }
+            (_, SchemaKind::List) | (_, SchemaKind::Array) | (_, SchemaKind::Set) => {
+                let Some(items) = value.as_array() else {
+                    return error("expected an array".into());
+                };
+
+                if let Some(len) = array_len(type_path).filter(|len| *len != items.len()) {
+                    return error(format!("expected {len} items, got {}", items.len()));
+                }
+
+                match &bevy_type.items {
+                    Some(item_ref) => items.iter().enumerate().try_for_each(|(idx, item)| {
+                        self.check_ref(item, item_ref, format!("{path}[{idx}]"))
+                    }),
+                    None => Ok(()),
+                }
+            }
+            (_, SchemaKind::Map) => {
+                let Some(map) = value.as_object() else {
+                    return error("expected an object".into());
+                };
+
+                if let Some(key_ref) = &bevy_type.key_type {
+                    map.keys()
+                        .try_for_each(|key| self.check_key(key, key_ref, &path))?;
+                }
+
+                match &bevy_type.value_type {
+                    Some(value_ref) => map.iter().try_for_each(|(key, item)| {
+                        self.check_ref(item, value_ref, format!("{path}[{key:?}]"))
+                    }),
+                    None => Ok(()),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Map keys are JSON object keys, so numeric and bool keys are strings
+    /// that must parse as their type.
+    fn check_key(&self, key: &str, key_ref: &Value, path: &str) -> Result<(), ValidationError> {
+        let Some(key_type) =
+            ref_type_path(key_ref).and_then(|type_path| self.schema.get(type_path))
+        else {
+            return Ok(());
+        };
+        let key_value = match key_type.schema_type {
+            SchemaType::String => Value::String(key.to_string()),
+            _ => serde_json::from_str(key).unwrap_or_else(|_| Value::String(key.to_string())),
+        };
+
+        self.check(&key_value, key_type, format!("{path}[{key:?}]"))
+            .map_err(|error| ValidationError {
+                message: format!("invalid key: {}", error.message),
+                ..error
+            })
+    }
+
+    /// Unit variants are serialized as `"Variant"`, the rest as
+    /// `{ "Variant": payload }`.
+    fn check_enum(
+        &self,
+        value: &Value,
+        bevy_type: &JsonSchemaBevyType,
+        path: String,
+    ) -> Result<(), ValidationError> {
+        let (name, payload) = match value {
+            Value::String(name) => (name.as_str(), None),
+            Value::Object(object) if object.len() == 1 => object
+                .iter()
+                .next()
+                .map(|(name, payload)| (name.as_str(), Some(payload)))
+                .unwrap_or_default(),
+            _ => {
+                return Err(ValidationError {
+                    path,
+                    message: format!("expected a `{}` variant", bevy_type.short_path),
+                });
+            }
+        };
+
+        let Some(variant) = bevy_type
+            .one_of
+            .iter()
+            .find(|variant| variant.get("shortPath").and_then(Value::as_str) == Some(name))
+        else {
+            return Err(ValidationError {
+                path,
+                message: format!("unknown variant `{name}` of `{}`", bevy_type.short_path),
+            });
+        };
+
+        let kind = variant.get("kind").and_then(Value::as_str);
+        let message = match (kind, payload) {
+            (Some("Struct"), Some(payload)) if payload.is_object() => {
+                let variant = serde_json::from_value::<JsonSchemaBevyType>(variant.clone())
+                    .unwrap_or_default();
+                return self.check_fields(payload, &variant, path);
+            }
+            (Some("Tuple"), Some(payload)) => {
+                let prefix_items = variant
+                    .get("prefixItems")
+                    .and_then(Value::as_array)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+
+                return match prefix_items {
+                    [type_ref] => self.check_ref(payload, type_ref, format!("{path}.0")),
+                    prefix_items => self.check_prefix_items(payload, prefix_items, path),
+                };
+            }
+            (None, None) => return Ok(()),
+            (None, Some(_)) => format!("unit variant `{name}` has no fields"),
+            (Some(_), _) => format!("variant `{name}` is missing its fields"),
+        };
+
+        Err(ValidationError { path, message })
+    }
+
+    fn check_fields(
+        &self,
+        value: &Value,
+        bevy_type: &JsonSchemaBevyType,
+        path: String,
+    ) -> Result<(), ValidationError> {
+        let object = value.as_object().cloned().unwrap_or_default();
+
+        if let Some(field) = bevy_type
+            .required
+            .iter()
+            .find(|field| !object.contains_key(field.as_str()))
+        {
+            return Err(ValidationError {
+                path,
+                message: format!("missing field `{field}`"),
+            });
+        }
+
+        for (key, item) in &object {
+            match bevy_type.properties.get(key) {
+                Some(type_ref) => self.check_ref(item, type_ref, format!("{path}.{key}"))?,
+                None if bevy_type.additional_properties == Some(false) => {
+                    return Err(ValidationError {
+                        path,
+                        message: format!("unknown field `{key}`"),
+                    });
+                }
+                None => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_prefix_items(
+        &self,
+        value: &Value,
+        prefix_items: &[Value],
+        path: String,
+    ) -> Result<(), ValidationError> {
+        match value.as_array() {
+            Some(items) if items.len() == prefix_items.len() => {
+                items.iter().zip(prefix_items).enumerate().try_for_each(
+                    |(idx, (item, type_ref))| {
+                        self.check_ref(item, type_ref, format!("{path}.{idx}"))
+                    },
+                )
+            }
+            _ => Err(ValidationError {
+                path,
+                message: format!("expected an array of {} items", prefix_items.len()),
+            }),
+        }
+    }
+}
+
+/// The payload type of `Option::Some`.
+fn some_type_ref(bevy_type: &JsonSchemaBevyType) -> Option<&Value> {
+    bevy_type
+        .one_of
+        .iter()
+        .find(|variant| variant.get("shortPath").and_then(Value::as_str) == Some("Some"))?
+        .get("prefixItems")?
+        .get(0)
+}
+
+fn check_int(value: &Value, type_path: &str) -> Result<(), String> {
+    let Some(number) = value
+        .as_i64()
+        .map(i128::from)
+        .or_else(|| value.as_u64().map(i128::from))
+    else {
+        return Err(if value.is_number() {
+            format!("expected an integer, got {value}")
+        } else {
+            "expected an integer".into()
+        });
+    };
+
+    let (min, max) = match type_path {
+        "u8" => (0, u8::MAX as i128),
+        "u16" => (0, u16::MAX as i128),
+        "u32" => (0, u32::MAX as i128),
+        "u64" | "usize" | "u128" => (0, i128::MAX),
+        "i8" => (i8::MIN as i128, i8::MAX as i128),
+        "i16" => (i16::MIN as i128, i16::MAX as i128),
+        "i32" => (i32::MIN as i128, i32::MAX as i128),
+        _ => (i128::MIN, i128::MAX),
+    };
+
+    if number < min || number > max {
+        return Err(format!(
+            "{number} is out of range for `{type_path}` ({min}..={max})"
+        ));
+    }
+
+    Ok(())
+}
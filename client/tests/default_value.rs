@@ -0,0 +1,199 @@
+use client::{
+    JsonSchemaBevyType, default_value, default_value_for_ref, default_variant_value, from_str,
+    from_value, json,
+};
+use std::collections::BTreeMap;
+
+const SCHEMA: &str = include_str!("../../mock_server/fixtures/schema.json");
+
+/// Fixtures schema, plus a few types it doesn't cover.
+fn schema() -> BTreeMap<String, JsonSchemaBevyType> {
+    let mut schema = from_str::<BTreeMap<String, JsonSchemaBevyType>>(SCHEMA).unwrap();
+    let extra = from_value::<BTreeMap<String, JsonSchemaBevyType>>(json!({
+        "core::option::Option<f32>": {
+            "shortPath": "Option<f32>",
+            "typePath": "core::option::Option<f32>",
+            "kind": "Enum",
+            "type": "object",
+            "oneOf": [
+                { "typePath": "core::option::Option<f32>::None", "shortPath": "None" },
+                {
+                    "type": "array",
+                    "kind": "Tuple",
+                    "typePath": "core::option::Option<f32>::Some",
+                    "shortPath": "Some",
+                    "prefixItems": [{ "type": { "$ref": "#/$defs/f32" } }],
+                    "items": false
+                }
+            ]
+        },
+        "mock::Shape": {
+            "shortPath": "Shape",
+            "typePath": "mock::Shape",
+            "kind": "Enum",
+            "type": "object",
+            "oneOf": [
+                {
+                    "type": "object",
+                    "kind": "Struct",
+                    "typePath": "mock::Shape::Circle",
+                    "shortPath": "Circle",
+                    "properties": { "radius": { "type": { "$ref": "#/$defs/f32" } } },
+                    "required": ["radius"],
+                    "additionalProperties": false
+                },
+                {
+                    "type": "array",
+                    "kind": "Tuple",
+                    "typePath": "mock::Shape::Rect",
+                    "shortPath": "Rect",
+                    "prefixItems": [
+                        { "type": { "$ref": "#/$defs/f32" } },
+                        { "type": { "$ref": "#/$defs/f32" } }
+                    ],
+                    "items": false
+                },
+                { "typePath": "mock::Shape::Empty", "shortPath": "Empty" }
+            ]
+        },
+        "glam::DQuat": {
+            "shortPath": "DQuat",
+            "typePath": "glam::DQuat",
+            "kind": "Struct",
+            "type": "object",
+            "properties": {
+                "w": { "type": { "$ref": "#/$defs/f64" } },
+                "x": { "type": { "$ref": "#/$defs/f64" } },
+                "y": { "type": { "$ref": "#/$defs/f64" } },
+                "z": { "type": { "$ref": "#/$defs/f64" } }
+            },
+            "required": ["x", "y", "z", "w"]
+        },
+        "mock::Node": {
+            "shortPath": "Node",
+            "typePath": "mock::Node",
+            "kind": "Struct",
+            "type": "object",
+            "properties": {
+                "next": { "type": { "$ref": "#/$defs/mock::Node" } },
+                "value": { "type": { "$ref": "#/$defs/u8" } }
+            },
+            "required": ["next", "value"]
+        }
+    }))
+    .unwrap();
+
+    schema.extend(extra);
+    schema
+}
+
+#[test]
+fn default_value_primitives() {
+    let schema = schema();
+
+    assert_eq!(default_value("bool", &schema), Some(json!(false)));
+    assert_eq!(default_value("f32", &schema), Some(json!(0.0)));
+    assert_eq!(default_value("u8", &schema), Some(json!(0)));
+    assert_eq!(
+        default_value("alloc::string::String", &schema),
+        Some(json!(""))
+    );
+    assert_eq!(default_value("mock::Unknown", &schema), None);
+}
+
+#[test]
+fn default_value_structs() {
+    let schema = schema();
+
+    assert_eq!(
+        default_value("mock::Health", &schema),
+        Some(json!({ "current": 0.0, "max": 0.0 }))
+    );
+    assert_eq!(
+        default_value("bevy_transform::components::transform::Transform", &schema),
+        Some(json!({
+            "translation": [0.0, 0.0, 0.0],
+            "rotation": [0.0, 0.0, 0.0, 1.0],
+            "scale": [1.0, 1.0, 1.0],
+        }))
+    );
+}
+
+#[test]
+fn default_value_quaternions_are_identity() {
+    let schema = schema();
+
+    assert_eq!(
+        default_value("glam::Quat", &schema),
+        Some(json!([0.0, 0.0, 0.0, 1.0]))
+    );
+    assert_eq!(
+        default_value("glam::DQuat", &schema),
+        Some(json!([0.0, 0.0, 0.0, 1.0]))
+    );
+}
+
+#[test]
+fn default_value_enums() {
+    let schema = schema();
+
+    assert_eq!(default_value("mock::Team", &schema), Some(json!("Red")));
+    assert_eq!(
+        default_value("mock::Shape", &schema),
+        Some(json!({ "Circle": { "radius": 0.0 } }))
+    );
+    assert_eq!(
+        default_value("core::option::Option<f32>", &schema),
+        Some(json!(null))
+    );
+}
+
+#[test]
+fn default_variant_value_picks_variant() {
+    let schema = schema();
+
+    assert_eq!(
+        default_variant_value("mock::Shape", "Rect", &schema),
+        Some(json!({ "Rect": [0.0, 0.0] }))
+    );
+    assert_eq!(
+        default_variant_value("mock::Shape", "Empty", &schema),
+        Some(json!("Empty"))
+    );
+    assert_eq!(
+        default_variant_value("core::option::Option<f32>", "Some", &schema),
+        Some(json!(0.0))
+    );
+    assert_eq!(
+        default_variant_value("mock::Team", "Blue", &schema),
+        Some(json!("Blue"))
+    );
+    assert_eq!(default_variant_value("mock::Team", "Green", &schema), None);
+}
+
+#[test]
+fn default_value_collections_and_refs() {
+    let schema = schema();
+
+    assert_eq!(
+        default_value("alloc::vec::Vec<bevy_ecs::entity::Entity>", &schema),
+        Some(json!([]))
+    );
+    assert_eq!(
+        default_value_for_ref(
+            &json!({ "type": { "$ref": "#/$defs/mock::Health" } }),
+            &schema
+        ),
+        Some(json!({ "current": 0.0, "max": 0.0 }))
+    );
+}
+
+#[test]
+fn default_value_recursive_type() {
+    let schema = schema();
+
+    assert_eq!(
+        default_value("mock::Node", &schema),
+        Some(json!({ "next": null, "value": 0 }))
+    );
+}
@@ -0,0 +1,358 @@
+use client::{
+    BrpClient, BrpQuery, ClientError, Entity, ErrorCode, Script, component, from_str, from_value,
+    json,
+};
+use futures::{FutureExt, StreamExt, executor::block_on};
+use mock_server::MockServer;
+use std::{collections::BTreeMap, thread};
+
+const WORLD: &str = include_str!("../../mock_server/fixtures/world.json");
+const SCHEMA: &str = include_str!("../../mock_server/fixtures/schema.json");
+
+const HEALTH: &str = "mock::Health";
+const SETTINGS: &str = "mock::GameSettings";
+const TEAM: &str = "mock::Team";
+const TRANSFORM: &str = "bevy_transform::components::transform::Transform";
+
+/// Client connected to a mock server loaded with the fixtures.
+fn client() -> BrpClient {
+    let server = MockServer::new(from_str(WORLD).unwrap(), from_str(SCHEMA).unwrap());
+    let (transport, mut requests) = client::transport::ChannelTransport::new();
+
+    thread::spawn(move || {
+        while let Some(request) = block_on(requests.next()) {
+            let _ = request
+                .responder
+                .unbounded_send(server.handle(request.request));
+        }
+    });
+
+    BrpClient::with_transport(transport)
+}
+
+fn entity(bits: u64) -> Entity {
+    from_value(json!(bits)).unwrap()
+}
+
+fn names(entities: &[client::EntityItem]) -> Vec<String> {
+    entities.iter().filter_map(|entity| entity.name()).collect()
+}
+
+#[test]
+fn ping() {
+    block_on(client().ping()).unwrap();
+}
+
+#[test]
+fn futures_are_send() {
+    let brp = client();
+    // Created here, polled on another thread, as multi-threaded executors do
+    let ping = {
+        let brp = brp.clone();
+        async move { brp.ping().await }
+    };
+    let watch = async move {
+        let mut updates = brp.watch_entity(entity(1)).await?;
+
+        updates.next().await.unwrap().map(|_| ())
+    };
+
+    thread::spawn(move || block_on(ping))
+        .join()
+        .unwrap()
+        .unwrap();
+
+    // The mock server has no `+watch` methods
+    let error = thread::spawn(move || block_on(watch))
+        .join()
+        .unwrap()
+        .unwrap_err();
+
+    assert_eq!(error.code(), Some(ErrorCode::MethodNotFound));
+}
+
+#[test]
+fn get_schema() {
+    let schema = block_on(client().get_schema()).unwrap();
+
+    assert_eq!(schema[HEALTH].short_path, "Health");
+    assert!(schema.contains_key(TRANSFORM));
+}
+
+#[test]
+fn get_root_entities() {
+    let roots = block_on(client().get_children(None)).unwrap();
+
+    assert_eq!(names(&roots), ["Camera", "Player", "Enemy"]);
+}
+
+#[test]
+fn get_children() {
+    let children = block_on(client().get_children(Some(entity(2)))).unwrap();
+
+    assert_eq!(names(&children), ["Sword"]);
+}
+
+#[test]
+fn get_entity() {
+    let enemy = block_on(client().get(entity(4))).unwrap();
+
+    assert_eq!(enemy.name().as_deref(), Some("Enemy"));
+    assert_eq!(
+        enemy.get_component_as::<String>(TEAM).as_deref(),
+        Some("Blue")
+    );
+    // Components that fail to serialize are still listed
+    assert_eq!(enemy.components.get("mock::Opaque"), Some(&None));
+}
+
+#[test]
+fn get_missing_entity() {
+    let error = block_on(client().get(entity(42))).unwrap_err();
+
+    assert_eq!(error.code(), Some(ErrorCode::EntityNotFound));
+}
+
+#[test]
+fn mutate_component() {
+    let client = client();
+
+    block_on(client.mutate_component(
+        entity(2),
+        HEALTH.to_string(),
+        ".current".to_string(),
+        json!(25.0),
+    ))
+    .unwrap();
+    block_on(client.mutate_component(
+        entity(2),
+        TRANSFORM.to_string(),
+        ".translation.y".to_string(),
+        json!(3.0),
+    ))
+    .unwrap();
+
+    let player = block_on(client.get(entity(2))).unwrap();
+
+    assert_eq!(
+        player.components[HEALTH],
+        Some(json!({ "current": 25.0, "max": 100.0 }))
+    );
+    assert_eq!(
+        player.components[TRANSFORM].as_ref().unwrap()["translation"],
+        json!([0.0, 3.0, 0.0])
+    );
+}
+
+#[test]
+fn mutate_invalid_path() {
+    let error = block_on(client().mutate_component(
+        entity(2),
+        HEALTH.to_string(),
+        ".armor".to_string(),
+        json!(1.0),
+    ))
+    .unwrap_err();
+
+    assert_eq!(error.code(), Some(ErrorCode::ComponentError));
+}
+
+#[test]
+fn query() {
+    let client = client();
+    let query = BrpQuery::new()
+        .components([HEALTH])
+        .option([component::NAME])
+        .without([TEAM]);
+
+    assert!(block_on(client.query(&query)).unwrap().is_empty());
+
+    let query = BrpQuery::new()
+        .components([HEALTH])
+        .option([component::NAME]);
+    let rows = block_on(client.query(&query)).unwrap();
+
+    assert_eq!(names(&rows), ["Player", "Enemy"]);
+    assert!(!rows[0].has_component(TRANSFORM));
+}
+
+#[test]
+fn spawn_and_despawn_recursive() {
+    let client = client();
+    let components = BTreeMap::from([(component::NAME.to_string(), json!("Shield"))]);
+    let shield = block_on(client.spawn(components)).unwrap();
+
+    assert_eq!(
+        block_on(client.get(shield)).unwrap().name().as_deref(),
+        Some("Shield")
+    );
+
+    block_on(client.despawn_recursive(entity(2))).unwrap();
+
+    let roots = block_on(client.get_children(None)).unwrap();
+
+    assert_eq!(names(&roots), ["Camera", "Enemy", "Shield"]);
+    assert!(block_on(client.get(entity(3))).is_err());
+}
+
+#[test]
+fn insert_and_remove_components() {
+    let client = client();
+    let components = BTreeMap::from([(TEAM.to_string(), json!("Red"))]);
+
+    block_on(client.insert_components(entity(1), components)).unwrap();
+    assert!(
+        block_on(client.list_components(entity(1)))
+            .unwrap()
+            .contains(&TEAM.to_string())
+    );
+
+    block_on(client.remove_components(entity(1), vec![TEAM.to_string()])).unwrap();
+    assert!(
+        !block_on(client.list_components(entity(1)))
+            .unwrap()
+            .contains(&TEAM.to_string())
+    );
+}
+
+#[test]
+fn resources() {
+    let client = client();
+
+    assert_eq!(block_on(client.list_resources()).unwrap(), [SETTINGS]);
+    assert_eq!(
+        block_on(client.get_resource(SETTINGS.to_string())).unwrap()["difficulty"],
+        json!(2)
+    );
+}
+
+#[test]
+fn mutate_resource() {
+    let client = client();
+
+    block_on(client.mutate_resource(SETTINGS.to_string(), ".paused".to_string(), json!(true)))
+        .unwrap();
+
+    assert_eq!(
+        block_on(client.get_resource(SETTINGS.to_string())).unwrap()["paused"],
+        json!(true)
+    );
+}
+
+#[test]
+fn insert_and_remove_resource() {
+    let client = client();
+    let resource = "mock::Score".to_string();
+
+    block_on(client.insert_resource(resource.clone(), json!(10))).unwrap();
+    assert_eq!(
+        block_on(client.get_resource(resource.clone())).unwrap(),
+        json!(10)
+    );
+
+    block_on(client.remove_resource(resource.clone())).unwrap();
+
+    let error = block_on(client.get_resource(resource)).unwrap_err();
+
+    assert_eq!(error.code(), Some(ErrorCode::ResourceNotPresent));
+}
+
+#[test]
+fn watch_unsupported() {
+    // The mock server doesn't stream, watchers must fail so callers can poll
+    let client = client();
+    let mut updates = block_on(client.watch_entity(entity(2))).unwrap();
+    let error = block_on(updates.next()).unwrap().unwrap_err();
+
+    assert_eq!(error.code(), Some(ErrorCode::MethodNotFound));
+}
+
+#[test]
+fn watch_without_changes() {
+    // Like Bevy, `+watch` requests get no response until something changes
+    let server = MockServer::new(from_str(WORLD).unwrap(), from_str(SCHEMA).unwrap());
+    let (transport, mut requests) = client::transport::ChannelTransport::new();
+
+    thread::spawn(move || {
+        let mut watchers = vec![];
+
+        while let Some(request) = block_on(requests.next()) {
+            let is_watch = request.request["method"]
+                .as_str()
+                .is_some_and(|method| method.ends_with("+watch"));
+
+            if is_watch {
+                watchers.push(request.responder);
+            } else {
+                let _ = request
+                    .responder
+                    .unbounded_send(server.handle(request.request));
+            }
+        }
+    });
+
+    let client = BrpClient::with_transport(transport);
+    let mut updates = block_on(client.watch_entity(entity(2))).unwrap();
+
+    assert!(updates.next().now_or_never().is_none());
+}
+
+#[test]
+fn replay() {
+    let client = client();
+    let script: Script = from_str(
+        r#"{
+            "steps": [
+                {
+                    "op": "mutate_component",
+                    "entity": { "name": "Enemy" },
+                    "component": "mock::Health",
+                    "path": ".current",
+                    "value": 10.0
+                },
+                {
+                    "op": "spawn",
+                    "components": { "bevy_ecs::name::Name": "Boss" }
+                },
+                {
+                    "op": "insert_components",
+                    "entity": { "name": "Boss" },
+                    "components": { "mock::Team": "Blue" }
+                },
+                {
+                    "op": "mutate_resource",
+                    "resource": "mock::GameSettings",
+                    "path": ".difficulty",
+                    "value": 3
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    block_on(client.replay(&script)).unwrap();
+
+    let enemy = block_on(client.get(entity(4))).unwrap();
+    let query = BrpQuery::new().components([TEAM]).option([component::NAME]);
+    let teams = block_on(client.query(&query)).unwrap();
+    let settings = block_on(client.get_resource(SETTINGS.to_string())).unwrap();
+
+    assert_eq!(
+        enemy.components[HEALTH].as_ref().unwrap()["current"],
+        json!(10.0)
+    );
+    assert_eq!(names(&teams), ["Player", "Enemy", "Boss"]);
+    assert_eq!(settings["difficulty"], json!(3));
+}
+
+#[test]
+fn replay_unknown_name() {
+    let script: Script =
+        from_str(r#"{ "steps": [{ "op": "despawn", "entity": { "name": "Ghost" } }] }"#).unwrap();
+    let error = block_on(client().replay(&script)).unwrap_err();
+
+    assert!(matches!(
+        error,
+        ClientError::ReplayError { step: 0, error } if matches!(*error, ClientError::UnknownEntityName(_))
+    ));
+}
@@ -0,0 +1,287 @@
+use client::{JsonSchemaBevyType, ValidationError, Value, from_str, from_value, json, validate};
+use std::collections::BTreeMap;
+
+const SCHEMA: &str = include_str!("../../mock_server/fixtures/schema.json");
+
+/// Fixtures schema, plus a few types it doesn't cover.
+fn schema() -> BTreeMap<String, JsonSchemaBevyType> {
+    let mut schema = from_str::<BTreeMap<String, JsonSchemaBevyType>>(SCHEMA).unwrap();
+    let extra = from_value::<BTreeMap<String, JsonSchemaBevyType>>(json!({
+        "core::option::Option<u8>": {
+            "shortPath": "Option<u8>",
+            "typePath": "core::option::Option<u8>",
+            "kind": "Enum",
+            "type": "object",
+            "oneOf": [
+                { "typePath": "core::option::Option<u8>::None", "shortPath": "None" },
+                {
+                    "type": "array",
+                    "kind": "Tuple",
+                    "typePath": "core::option::Option<u8>::Some",
+                    "shortPath": "Some",
+                    "prefixItems": [{ "type": { "$ref": "#/$defs/u8" } }],
+                    "items": false
+                }
+            ]
+        },
+        "mock::Shape": {
+            "shortPath": "Shape",
+            "typePath": "mock::Shape",
+            "kind": "Enum",
+            "type": "object",
+            "oneOf": [
+                {
+                    "type": "object",
+                    "kind": "Struct",
+                    "typePath": "mock::Shape::Circle",
+                    "shortPath": "Circle",
+                    "properties": { "radius": { "type": { "$ref": "#/$defs/f32" } } },
+                    "required": ["radius"],
+                    "additionalProperties": false
+                },
+                {
+                    "type": "array",
+                    "kind": "Tuple",
+                    "typePath": "mock::Shape::Rect",
+                    "shortPath": "Rect",
+                    "prefixItems": [
+                        { "type": { "$ref": "#/$defs/f32" } },
+                        { "type": { "$ref": "#/$defs/f32" } }
+                    ],
+                    "items": false
+                },
+                {
+                    "type": "array",
+                    "kind": "Tuple",
+                    "typePath": "mock::Shape::Dots",
+                    "shortPath": "Dots",
+                    "prefixItems": [{ "type": { "$ref": "#/$defs/u8" } }],
+                    "items": false
+                },
+                { "typePath": "mock::Shape::Empty", "shortPath": "Empty" }
+            ]
+        },
+        "glam::U8Vec2": {
+            "shortPath": "U8Vec2",
+            "typePath": "glam::U8Vec2",
+            "kind": "Struct",
+            "type": "object",
+            "properties": {
+                "x": { "type": { "$ref": "#/$defs/u8" } },
+                "y": { "type": { "$ref": "#/$defs/u8" } }
+            },
+            "required": ["x", "y"],
+            "additionalProperties": false
+        },
+        "[u8; 3]": {
+            "shortPath": "[u8; 3]",
+            "typePath": "[u8; 3]",
+            "kind": "Array",
+            "type": "array",
+            "items": { "type": { "$ref": "#/$defs/u8" } }
+        },
+        "std::collections::HashMap<u8, f32>": {
+            "shortPath": "HashMap<u8, f32>",
+            "typePath": "std::collections::HashMap<u8, f32>",
+            "kind": "Map",
+            "type": "object",
+            "keyType": { "type": { "$ref": "#/$defs/u8" } },
+            "valueType": { "type": { "$ref": "#/$defs/f32" } }
+        },
+        "std::collections::HashMap<alloc::string::String, u8>": {
+            "shortPath": "HashMap<String, u8>",
+            "typePath": "std::collections::HashMap<alloc::string::String, u8>",
+            "kind": "Map",
+            "type": "object",
+            "keyType": { "type": { "$ref": "#/$defs/alloc::string::String" } },
+            "valueType": { "type": { "$ref": "#/$defs/u8" } }
+        }
+    }))
+    .unwrap();
+
+    schema.extend(extra);
+    schema
+}
+
+fn check(type_path: &str, value: Value) -> Result<(), ValidationError> {
+    let schema = schema();
+
+    validate(&value, &schema[type_path], &schema)
+}
+
+fn message(type_path: &str, value: Value) -> String {
+    check(type_path, value).unwrap_err().to_string()
+}
+
+#[test]
+fn validate_primitives() {
+    assert!(check("bool", json!(true)).is_ok());
+    assert!(check("f32", json!(1.5)).is_ok());
+    assert!(check("alloc::string::String", json!("Player")).is_ok());
+    assert_eq!(message("bool", json!(1)), "expected a boolean");
+    assert_eq!(
+        message("alloc::string::String", json!(1)),
+        "expected a string"
+    );
+}
+
+#[test]
+fn validate_integer_ranges() {
+    assert!(check("u8", json!(255)).is_ok());
+    assert_eq!(
+        message("u8", json!(256)),
+        "256 is out of range for `u8` (0..=255)"
+    );
+    assert_eq!(
+        message("u8", json!(-1)),
+        "-1 is out of range for `u8` (0..=255)"
+    );
+    assert_eq!(message("u32", json!(1.5)), "expected an integer, got 1.5");
+}
+
+#[test]
+fn validate_structs() {
+    assert!(check("mock::Health", json!({ "current": 10.0, "max": 100.0 })).is_ok());
+    assert_eq!(
+        message("mock::Health", json!({ "current": 10.0 })),
+        "missing field `max`"
+    );
+    assert_eq!(
+        message(
+            "mock::Health",
+            json!({ "current": 10.0, "max": 1.0, "regen": 1.0 })
+        ),
+        "unknown field `regen`"
+    );
+    assert_eq!(
+        message(
+            "mock::GameSettings",
+            json!({ "difficulty": 300, "paused": false, "title": "Mock" })
+        ),
+        "`.difficulty`: 300 is out of range for `u8` (0..=255)"
+    );
+}
+
+#[test]
+fn validate_enums_and_glam_types() {
+    assert!(check("mock::Team", json!("Blue")).is_ok());
+    assert_eq!(
+        message("mock::Team", json!("Green")),
+        "expected one of \"Red\", \"Blue\""
+    );
+    assert_eq!(
+        message(
+            "bevy_transform::components::transform::Transform",
+            json!({
+                "translation": [0.0, 0.0, 0.0],
+                "rotation": "identity",
+                "scale": [1.0, 1.0, 1.0],
+            })
+        ),
+        "`.rotation`: expected an array of numbers for `Quat`"
+    );
+}
+
+#[test]
+fn validate_enum_payloads() {
+    assert!(check("mock::Shape", json!({ "Circle": { "radius": 1.0 } })).is_ok());
+    assert!(check("mock::Shape", json!({ "Rect": [1.0, 2.0] })).is_ok());
+    assert!(check("mock::Shape", json!({ "Dots": 3 })).is_ok());
+    assert!(check("mock::Shape", json!("Empty")).is_ok());
+    assert_eq!(
+        message("mock::Shape", json!({ "Circle": { "radius": "big" } })),
+        "`.radius`: expected a number"
+    );
+    assert_eq!(
+        message("mock::Shape", json!({ "Circle": {} })),
+        "missing field `radius`"
+    );
+    assert_eq!(
+        message("mock::Shape", json!({ "Rect": [1.0] })),
+        "expected an array of 2 items"
+    );
+    assert_eq!(
+        message("mock::Shape", json!({ "Dots": 300 })),
+        "`.0`: 300 is out of range for `u8` (0..=255)"
+    );
+    assert_eq!(
+        message("mock::Shape", json!("Circle")),
+        "variant `Circle` is missing its fields"
+    );
+    assert_eq!(
+        message("mock::Shape", json!({ "Empty": 1 })),
+        "unit variant `Empty` has no fields"
+    );
+    assert_eq!(
+        message("mock::Shape", json!({ "Line": [] })),
+        "unknown variant `Line` of `Shape`"
+    );
+    assert_eq!(
+        message("mock::Shape", json!(1)),
+        "expected a `Shape` variant"
+    );
+}
+
+#[test]
+fn validate_options() {
+    assert!(check("core::option::Option<u8>", json!(null)).is_ok());
+    assert!(check("core::option::Option<u8>", json!(7)).is_ok());
+    assert_eq!(
+        message("core::option::Option<u8>", json!(256)),
+        "256 is out of range for `u8` (0..=255)"
+    );
+    assert_eq!(
+        message("core::option::Option<u8>", json!("7")),
+        "expected an integer"
+    );
+}
+
+#[test]
+fn validate_glam_axes() {
+    assert!(check("glam::U8Vec2", json!([1, 255])).is_ok());
+    assert_eq!(
+        message("glam::U8Vec2", json!([1, -1])),
+        "`.y`: -1 is out of range for `u8` (0..=255)"
+    );
+    assert_eq!(
+        message("glam::U8Vec2", json!([1, 2, 3])),
+        "expected 2 items, got 3"
+    );
+}
+
+#[test]
+fn validate_fixed_size_arrays() {
+    assert!(check("[u8; 3]", json!([1, 2, 3])).is_ok());
+    assert_eq!(message("[u8; 3]", json!([1, 2])), "expected 3 items, got 2");
+    assert_eq!(
+        message("[u8; 3]", json!([1, 2, -3])),
+        "`[2]`: -3 is out of range for `u8` (0..=255)"
+    );
+    assert_eq!(message("[u8; 3]", json!({})), "expected an array");
+}
+
+#[test]
+fn validate_maps() {
+    let map = "std::collections::HashMap<u8, f32>";
+    let string_map = "std::collections::HashMap<alloc::string::String, u8>";
+
+    assert!(check(map, json!({ "1": 1.5, "255": 0.0 })).is_ok());
+    assert!(check(string_map, json!({ "key": 1, "2": 2 })).is_ok());
+    assert_eq!(
+        message(map, json!({ "1": "one" })),
+        "`[\"1\"]`: expected a number"
+    );
+    assert_eq!(
+        message(map, json!({ "one": 1.0 })),
+        "`[\"one\"]`: invalid key: expected an integer"
+    );
+    assert_eq!(
+        message(map, json!({ "256": 1.0 })),
+        "`[\"256\"]`: invalid key: 256 is out of range for `u8` (0..=255)"
+    );
+    assert_eq!(
+        message(string_map, json!({ "key": 256 })),
+        "`[\"key\"]`: 256 is out of range for `u8` (0..=255)"
+    );
+    assert_eq!(message(map, json!([])), "expected an object");
+}
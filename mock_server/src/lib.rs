@@ -0,0 +1,529 @@
+//! In-memory Bevy remote protocol (BRP) server, answers requests from a JSON
+//! world fixture and a `registry.schema` dump instead of a running game.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use std::{collections::BTreeMap, fs, io, path::Path, sync::Mutex};
+use thiserror::Error;
+
+mod path;
+
+/// Mirrors `bevy_remote::error_codes`.
+pub mod error_codes {
+    pub const METHOD_NOT_FOUND: i16 = -32601;
+    pub const INVALID_PARAMS: i16 = -32602;
+    pub const ENTITY_NOT_FOUND: i16 = -23401;
+    pub const COMPONENT_ERROR: i16 = -23402;
+    pub const COMPONENT_NOT_PRESENT: i16 = -23403;
+    pub const RESOURCE_ERROR: i16 = -23501;
+    pub const RESOURCE_NOT_PRESENT: i16 = -23502;
+}
+
+/// Methods answered by the mock server.
+pub const METHODS: [&str; 15] = [
+    "registry.schema",
+    "rpc.discover",
+    "world.despawn_entity",
+    "world.get_components",
+    "world.get_resources",
+    "world.insert_components",
+    "world.insert_resources",
+    "world.list_components",
+    "world.list_resources",
+    "world.mutate_components",
+    "world.mutate_resources",
+    "world.query",
+    "world.remove_components",
+    "world.remove_resources",
+    "world.spawn_entity",
+];
+
+#[derive(Debug, Error)]
+pub enum MockError {
+    #[error("failed to read fixture: {0}")]
+    Io(#[from] io::Error),
+    #[error("failed to parse fixture: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// World fixture, e.g.:
+///
+/// ```json
+/// {
+///   "entities": [
+///     { "entity": 1, "components": { "bevy_ecs::name::Name": "Player" } }
+///   ],
+///   "resources": { "my_game::Settings": { "volume": 0.5 } }
+/// }
+/// ```
+///
+/// Entities use the serialized `Entity` bits as ID, `0` isn't a valid one. A `null` component value
+/// simulates a component that can't be serialized.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct WorldFixture {
+    #[serde(default)]
+    pub entities: Vec<EntityFixture>,
+    #[serde(default)]
+    pub resources: BTreeMap<String, Value>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EntityFixture {
+    pub entity: u64,
+    #[serde(default)]
+    pub components: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Serialize)]
+struct BrpError {
+    code: i16,
+    message: String,
+}
+
+impl BrpError {
+    fn new(code: i16, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    fn entity_not_found(entity: u64) -> Self {
+        Self::new(
+            error_codes::ENTITY_NOT_FOUND,
+            format!("Entity {entity} not found"),
+        )
+    }
+
+    fn invalid_params(error: impl ToString) -> Self {
+        Self::new(error_codes::INVALID_PARAMS, error.to_string())
+    }
+}
+
+type BrpResult = Result<Value, BrpError>;
+
+#[derive(Deserialize)]
+struct EntityParams {
+    entity: u64,
+}
+
+#[derive(Deserialize)]
+struct GetComponentsParams {
+    entity: u64,
+    components: Vec<String>,
+    #[serde(default)]
+    strict: bool,
+}
+
+#[derive(Deserialize)]
+struct ComponentsParams<T> {
+    entity: u64,
+    components: T,
+}
+
+#[derive(Deserialize)]
+struct SpawnEntityParams {
+    components: BTreeMap<String, Value>,
+}
+
+#[derive(Deserialize)]
+struct MutateComponentsParams {
+    entity: u64,
+    component: String,
+    path: String,
+    value: Value,
+}
+
+#[derive(Deserialize)]
+struct ResourceParams {
+    resource: String,
+}
+
+#[derive(Deserialize)]
+struct InsertResourceParams {
+    resource: String,
+    value: Value,
+}
+
+#[derive(Deserialize)]
+struct MutateResourceParams {
+    resource: String,
+    path: String,
+    value: Value,
+}
+
+#[derive(Default, Deserialize)]
+struct QueryParams {
+    #[serde(default)]
+    data: QueryData,
+    #[serde(default)]
+    filter: QueryFilter,
+}
+
+#[derive(Default, Deserialize)]
+struct QueryData {
+    #[serde(default)]
+    components: Vec<String>,
+    /// Either a list of components or `"all"`
+    #[serde(default)]
+    option: Value,
+    #[serde(default)]
+    has: Vec<String>,
+}
+
+#[derive(Default, Deserialize)]
+struct QueryFilter {
+    #[serde(default)]
+    with: Vec<String>,
+    #[serde(default)]
+    without: Vec<String>,
+}
+
+#[derive(Default)]
+struct MockWorld {
+    entities: BTreeMap<u64, BTreeMap<String, Value>>,
+    resources: BTreeMap<String, Value>,
+}
+
+pub struct MockServer {
+    schema: Value,
+    world: Mutex<MockWorld>,
+}
+
+impl MockServer {
+    pub fn new(fixture: WorldFixture, schema: Value) -> Self {
+        let world = MockWorld {
+            entities: fixture
+                .entities
+                .into_iter()
+                .map(|entity| (entity.entity, entity.components))
+                .collect(),
+            resources: fixture.resources,
+        };
+
+        Self {
+            schema,
+            world: Mutex::new(world),
+        }
+    }
+
+    pub fn from_files(
+        world: impl AsRef<Path>,
+        schema: impl AsRef<Path>,
+    ) -> Result<Self, MockError> {
+        let world = serde_json::from_str(&fs::read_to_string(world)?)?;
+        let schema = serde_json::from_str(&fs::read_to_string(schema)?)?;
+
+        Ok(Self::new(world, schema))
+    }
+
+    /// Handles a JSON-RPC request, or an array of them for batch requests.
+    pub fn handle(&self, request: Value) -> Value {
+        match request {
+            Value::Array(requests) => Value::Array(
+                requests
+                    .into_iter()
+                    .map(|request| self.handle_one(request))
+                    .collect(),
+            ),
+            request => self.handle_one(request),
+        }
+    }
+
+    fn handle_one(&self, request: Value) -> Value {
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        let method = request
+            .get("method")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let params = request.get("params").filter(|params| !params.is_null());
+
+        match self.dispatch(method, params.cloned()) {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+        }
+    }
+
+    fn dispatch(&self, method: &str, params: Option<Value>) -> BrpResult {
+        let mut world = self.world.lock().unwrap();
+
+        match method {
+            "registry.schema" => Ok(self.schema.clone()),
+            "rpc.discover" => Ok(discover()),
+            "world.despawn_entity" => {
+                let EntityParams { entity } = parse(params)?;
+                world.despawn(entity)?;
+                Ok(Value::Null)
+            }
+            "world.get_components" => world.get_components(parse(params)?),
+            "world.get_resources" => {
+                let ResourceParams { resource } = parse(params)?;
+                let value = world
+                    .resources
+                    .get(&resource)
+                    .ok_or_else(|| resource_not_present(&resource))?;
+
+                Ok(json!({ "value": value }))
+            }
+            "world.insert_components" => {
+                let params: ComponentsParams<BTreeMap<String, Value>> = parse(params)?;
+                world
+                    .get_entity_mut(params.entity)?
+                    .extend(params.components);
+                Ok(Value::Null)
+            }
+            "world.insert_resources" => {
+                let InsertResourceParams { resource, value } = parse(params)?;
+                world.resources.insert(resource, value);
+                Ok(Value::Null)
+            }
+            "world.list_components" => match params {
+                Some(params) => {
+                    let EntityParams { entity } = parse(Some(params))?;
+                    let components = world.get_entity(entity)?.keys().collect::<Vec<_>>();
+                    Ok(json!(components))
+                }
+                None => Ok(json!(self.list_registered("Component"))),
+            },
+            "world.list_resources" => Ok(json!(world.resources.keys().collect::<Vec<_>>())),
+            "world.mutate_components" => {
+                let params: MutateComponentsParams = parse(params)?;
+                let component = world
+                    .get_entity_mut(params.entity)?
+                    .get_mut(&params.component)
+                    .ok_or_else(|| component_not_present(&params.component, params.entity))?;
+
+                *path::get_path_mut(component, &params.path).map_err(component_error)? =
+                    params.value;
+
+                Ok(Value::Null)
+            }
+            "world.mutate_resources" => {
+                let params: MutateResourceParams = parse(params)?;
+                let resource = world
+                    .resources
+                    .get_mut(&params.resource)
+                    .ok_or_else(|| resource_not_present(&params.resource))?;
+
+                *path::get_path_mut(resource, &params.path).map_err(resource_error)? = params.value;
+
+                Ok(Value::Null)
+            }
+            "world.query" => world.query(parse(params.or(Some(json!({}))))?),
+            "world.remove_components" => {
+                let params: ComponentsParams<Vec<String>> = parse(params)?;
+                let entity = world.get_entity_mut(params.entity)?;
+
+                for component in params.components {
+                    entity.remove(&component);
+                }
+
+                Ok(Value::Null)
+            }
+            "world.remove_resources" => {
+                let ResourceParams { resource } = parse(params)?;
+                world.resources.remove(&resource);
+                Ok(Value::Null)
+            }
+            "world.spawn_entity" => {
+                let params: SpawnEntityParams = parse(params)?;
+                let entity = world.entities.keys().last().map_or(1, |id| id + 1);
+
+                world.entities.insert(entity, params.components);
+
+                Ok(json!({ "entity": entity }))
+            }
+            _ => Err(BrpError::new(
+                error_codes::METHOD_NOT_FOUND,
+                format!("Method `{method}` not found"),
+            )),
+        }
+    }
+
+    /// Type paths in the schema that reflect the given trait, e.g. `Component`.
+    fn list_registered(&self, reflect_type: &str) -> Vec<String> {
+        let Some(schema) = self.schema.as_object() else {
+            return vec![];
+        };
+
+        schema
+            .iter()
+            .filter(|(_, bevy_type)| {
+                bevy_type
+                    .get("reflectTypes")
+                    .and_then(Value::as_array)
+                    .is_some_and(|types| types.iter().any(|ty| ty == reflect_type))
+            })
+            .map(|(type_path, _)| type_path.clone())
+            .collect()
+    }
+}
+
+impl MockWorld {
+    fn get_entity(&self, entity: u64) -> Result<&BTreeMap<String, Value>, BrpError> {
+        self.entities
+            .get(&entity)
+            .ok_or_else(|| BrpError::entity_not_found(entity))
+    }
+
+    fn get_entity_mut(&mut self, entity: u64) -> Result<&mut BTreeMap<String, Value>, BrpError> {
+        self.entities
+            .get_mut(&entity)
+            .ok_or_else(|| BrpError::entity_not_found(entity))
+    }
+
+    /// Despawns the entity along with its descendants, like Bevy does.
+    fn despawn(&mut self, entity: u64) -> Result<(), BrpError> {
+        let components = self
+            .entities
+            .remove(&entity)
+            .ok_or_else(|| BrpError::entity_not_found(entity))?;
+
+        if let Some(children) = components.get(CHILDREN).and_then(Value::as_array) {
+            for child in children.iter().filter_map(Value::as_u64) {
+                let _ = self.despawn(child);
+            }
+        }
+
+        // Remove it from its parent children
+        if let Some(parent) = components.get(CHILD_OF).and_then(Value::as_u64) {
+            let children = self
+                .entities
+                .get_mut(&parent)
+                .and_then(|parent| parent.get_mut(CHILDREN))
+                .and_then(Value::as_array_mut);
+
+            if let Some(children) = children {
+                children.retain(|child| child.as_u64() != Some(entity));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_components(&self, params: GetComponentsParams) -> BrpResult {
+        let entity = self.get_entity(params.entity)?;
+        let mut components = Map::new();
+        let mut errors = Map::new();
+
+        for component in params.components {
+            let error = match entity.get(&component) {
+                Some(Value::Null) => BrpError::new(
+                    error_codes::COMPONENT_ERROR,
+                    format!("Component `{component}` could not be serialized"),
+                ),
+                Some(value) => {
+                    components.insert(component, value.clone());
+                    continue;
+                }
+                None => component_not_present(&component, params.entity),
+            };
+
+            if params.strict {
+                return Err(error);
+            }
+
+            errors.insert(component, json!(error));
+        }
+
+        if params.strict {
+            Ok(Value::Object(components))
+        } else {
+            Ok(json!({ "components": components, "errors": errors }))
+        }
+    }
+
+    fn query(&self, params: QueryParams) -> BrpResult {
+        let QueryParams { data, filter } = params;
+        let rows = self
+            .entities
+            .iter()
+            .filter(|(_, components)| {
+                data.components
+                    .iter()
+                    .chain(filter.with.iter())
+                    .all(|component| components.contains_key(component))
+                    && !filter
+                        .without
+                        .iter()
+                        .any(|component| components.contains_key(component))
+            })
+            .map(|(entity, components)| {
+                let selected = components
+                    .iter()
+                    .filter(|(component, value)| {
+                        let is_optional = match &data.option {
+                            Value::String(all) => all == "all",
+                            Value::Array(option) => option.iter().any(|c| c == *component),
+                            _ => false,
+                        };
+
+                        !value.is_null() && (data.components.contains(component) || is_optional)
+                    })
+                    .map(|(component, value)| (component.clone(), value.clone()))
+                    .collect::<Map<_, _>>();
+
+                let mut row = json!({ "entity": entity, "components": selected });
+
+                if !data.has.is_empty() {
+                    row["has"] = data
+                        .has
+                        .iter()
+                        .map(|component| {
+                            (component.clone(), json!(components.contains_key(component)))
+                        })
+                        .collect::<Map<_, _>>()
+                        .into();
+                }
+
+                row
+            })
+            .collect::<Vec<_>>();
+
+        Ok(json!(rows))
+    }
+}
+
+const CHILD_OF: &str = "bevy_ecs::hierarchy::ChildOf";
+const CHILDREN: &str = "bevy_ecs::hierarchy::Children";
+
+fn parse<T: for<'de> Deserialize<'de>>(params: Option<Value>) -> Result<T, BrpError> {
+    let params = params.ok_or_else(|| BrpError::invalid_params("Params not provided"))?;
+    serde_json::from_value(params).map_err(BrpError::invalid_params)
+}
+
+fn component_not_present(component: &str, entity: u64) -> BrpError {
+    BrpError::new(
+        error_codes::COMPONENT_NOT_PRESENT,
+        format!("Component `{component}` not present in Entity {entity}"),
+    )
+}
+
+fn component_error(error: impl ToString) -> BrpError {
+    BrpError::new(error_codes::COMPONENT_ERROR, error.to_string())
+}
+
+fn resource_not_present(resource: &str) -> BrpError {
+    BrpError::new(
+        error_codes::RESOURCE_NOT_PRESENT,
+        format!("Resource `{resource}` not present in the world"),
+    )
+}
+
+fn resource_error(error: impl ToString) -> BrpError {
+    BrpError::new(error_codes::RESOURCE_ERROR, error.to_string())
+}
+
+fn discover() -> Value {
+    json!({
+        "openrpc": "1.3.2",
+        "info": {
+            "title": "Bevy Remote Protocol (mock)",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "methods": METHODS
+            .iter()
+            .map(|method| json!({ "name": method, "params": [] }))
+            .collect::<Vec<_>>(),
+    })
+}
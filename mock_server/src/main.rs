@@ -0,0 +1,115 @@
+//! Serves a [`MockServer`] over HTTP, so the app can be used without a game.
+//! From the workspace root:
+//!
+//! ```sh
+//! cargo run -p mock_server -- mock_server/fixtures/world.json mock_server/fixtures/schema.json
+//! ```
+
+use mock_server::MockServer;
+use serde_json::{Value, json};
+use std::{
+    env,
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    process,
+    sync::Arc,
+    thread,
+};
+
+const DEFAULT_ADDR: &str = "127.0.0.1:15702";
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let [world, schema, rest @ ..] = args.as_slice() else {
+        eprintln!("Usage: mock_server <world.json> <schema.json> [addr]");
+        process::exit(1);
+    };
+
+    let addr = rest.first().map_or(DEFAULT_ADDR, String::as_str);
+    let server = match MockServer::from_files(world, schema) {
+        Ok(server) => Arc::new(server),
+        Err(error) => {
+            eprintln!("{error}");
+            process::exit(1);
+        }
+    };
+
+    let listener = TcpListener::bind(addr).unwrap_or_else(|error| {
+        eprintln!("Failed to bind {addr}: {error}");
+        process::exit(1);
+    });
+
+    println!("Mock BRP server listening on http://{addr}");
+
+    for stream in listener.incoming().flatten() {
+        let server = server.clone();
+
+        thread::spawn(move || {
+            if let Err(error) = handle_connection(&server, stream) {
+                eprintln!("Connection error: {error}");
+            }
+        });
+    }
+}
+
+fn handle_connection(server: &MockServer, stream: TcpStream) -> std::io::Result<()> {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    let mut content_length = 0;
+
+    reader.read_line(&mut request_line)?;
+
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header)?;
+
+        let header = header.trim_end();
+
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse().unwrap_or(0);
+        }
+    }
+
+    // CORS preflight, the web build runs on a different origin
+    if request_line.starts_with("OPTIONS") {
+        return respond(&stream, "204 No Content", "");
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let response = match serde_json::from_slice::<Value>(&body) {
+        Ok(request) => server.handle(request),
+        Err(error) => json!({
+            "jsonrpc": "2.0",
+            "id": null,
+            "error": { "code": -32700, "message": error.to_string() },
+        }),
+    };
+
+    respond(&stream, "200 OK", &response.to_string())
+}
+
+fn respond(mut stream: &TcpStream, status: &str, body: &str) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Access-Control-Allow-Origin: *\r\n\
+         Access-Control-Allow-Methods: POST, OPTIONS\r\n\
+         Access-Control-Allow-Headers: *\r\n\
+         Connection: close\r\n\r\n\
+         {body}",
+        body.len()
+    )?;
+
+    stream.flush()
+}
@@ -0,0 +1,78 @@
+use serde_json::Value;
+
+/// A segment of a reflect path, e.g. `.translation` or `[0]`.
+#[derive(Debug, PartialEq)]
+enum Segment<'a> {
+    Field(&'a str),
+    Index(usize),
+}
+
+fn parse(path: &str) -> Result<Vec<Segment<'_>>, String> {
+    let mut segments = vec![];
+    let mut rest = path;
+
+    while !rest.is_empty() {
+        if let Some(field) = rest.strip_prefix('.') {
+            let end = field.find(['.', '[']).unwrap_or(field.len());
+
+            if end == 0 {
+                return Err(format!("Empty field name in path `{path}`"));
+            }
+
+            segments.push(Segment::Field(&field[..end]));
+            rest = &field[end..];
+        } else if let Some(index) = rest.strip_prefix('[') {
+            let end = index
+                .find(']')
+                .ok_or_else(|| format!("Unclosed `[` in path `{path}`"))?;
+            let value = index[..end]
+                .parse()
+                .map_err(|_| format!("Invalid index in path `{path}`"))?;
+
+            segments.push(Segment::Index(value));
+            rest = &index[end + 1..];
+        } else {
+            return Err(format!("Unexpected character in path `{path}`"));
+        }
+    }
+
+    Ok(segments)
+}
+
+/// Resolves a reflect path against a serialized value. Mimics how
+/// `bevy_reflect` paths map to the serialized form: glam vectors are arrays
+/// (`.x` is `[0]`) and newtypes are flattened (`.0` is the value itself).
+pub fn get_path_mut<'a>(value: &'a mut Value, path: &str) -> Result<&'a mut Value, String> {
+    let mut current = value;
+
+    for segment in parse(path)? {
+        current = match (segment, current) {
+            (Segment::Field(field), Value::Object(object)) => object
+                .get_mut(field)
+                .ok_or_else(|| format!("Field `{field}` not found"))?,
+            (Segment::Field(field), Value::Array(array)) => {
+                let index = match field {
+                    "x" => 0,
+                    "y" => 1,
+                    "z" => 2,
+                    "w" => 3,
+                    _ => field
+                        .parse::<usize>()
+                        .map_err(|_| format!("Field `{field}` not found"))?,
+                };
+
+                array
+                    .get_mut(index)
+                    .ok_or_else(|| format!("Field `{field}` not found"))?
+            }
+            (Segment::Field("0"), value) => value,
+            (Segment::Field(field), _) => return Err(format!("Field `{field}` not found")),
+            (Segment::Index(index), Value::Array(array)) => array
+                .get_mut(index)
+                .ok_or_else(|| format!("Index `{index}` out of bounds"))?,
+            (Segment::Index(index), _) => return Err(format!("Can't index `{index}` a non-list")),
+        };
+    }
+
+    Ok(current)
+}